@@ -1,7 +1,14 @@
 pub mod basic;
+pub mod compare;
+pub(crate) mod complex;
 pub mod constants;
 pub mod construct;
 pub mod point_on;
+pub mod point_set;
+pub mod polygon;
+pub mod scalar;
 pub mod transform;
 pub mod exception;
+pub mod fit;
+pub mod hull;
 pub mod trig;
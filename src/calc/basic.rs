@@ -1,11 +1,18 @@
 #![allow(non_snake_case)]
 
-use crate::objects::{Circle, Line, Point};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::objects::{Circle, Conic, Ellipse, Line, Point, Segment, AABB};
+
+#[cfg(feature = "serialize")]
+use serde::Serialize;
 
 use super::{
+    compare::ApproxEq,
     constants::EPSILON,
     construct::perp_bisect,
     exception::{CalcException, Result},
+    scalar::Scalar,
 };
 
 /// Test if two floats are _almost_ equal.
@@ -14,33 +21,216 @@ fn aprx_eq(a: f64, b: f64) -> bool {
     (a - b).abs() < EPSILON
 }
 
+/// A tolerance for approximate comparison, with an absolute and an optional relative part.
+/// Two floats `a`, `b` are equal under it if `|a - b| < abs`, or if `|a - b|` is at most
+/// `rel` times the larger of `|a|` and `|b|`.
+/// The default tolerance is the absolute `EPSILON`, which is what `PartialEq` uses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    pub abs: f64,
+    pub rel: Option<f64>,
+}
+
+impl Default for Tolerance {
+    #[inline]
+    fn default() -> Self {
+        Tolerance {
+            abs: EPSILON,
+            rel: None,
+        }
+    }
+}
+
+impl Tolerance {
+    /// Construct an absolute tolerance.
+    #[inline]
+    pub fn new(abs: f64) -> Self {
+        Tolerance { abs, rel: None }
+    }
+    /// Add a relative part to the tolerance.
+    #[inline]
+    pub fn with_relative(self, rel: f64) -> Self {
+        Tolerance {
+            rel: Some(rel),
+            ..self
+        }
+    }
+    /// Test if two floats are equal under the tolerance.
+    #[inline]
+    pub fn equal(self, a: f64, b: f64) -> bool {
+        let d = (a - b).abs();
+        d < self.abs || self.rel.is_some_and(|r| d <= r * a.abs().max(b.abs()))
+    }
+}
+
 /// Test if two lines are parallel.
 #[inline]
 pub fn is_parallel(l: Line, k: Line) -> bool {
     aprx_eq(l.a * k.b, l.b * k.a)
 }
 
+/// Test if two lines are parallel, under a given tolerance.
+#[inline]
+pub fn is_parallel_within(l: Line, k: Line, tol: Tolerance) -> bool {
+    tol.equal(l.a * k.b, l.b * k.a)
+}
+
+/// The orientation of three points: `1` if `A, B, C` turn counterclockwise, `-1` if
+/// clockwise, and `0` if they are collinear.
+/// The points are considered collinear when the cross product of `B - A` and `C - A`
+/// is within `EPSILON` of zero, so the width of the collinear band is governed by `EPSILON`.
+#[inline]
+pub fn orientation(A: Point, B: Point, C: Point) -> i32 {
+    let z = (B - A).cross(C - A);
+    if aprx_eq(z, 0.0) {
+        0
+    } else if z > 0.0 {
+        1
+    } else {
+        -1
+    }
+}
+
+/// The signed parameter of a Point along `AB`, with `A` at `0` and `B` at `1`.
+/// The Points should be collinear and `A`, `B` should not overlap.
+#[inline]
+fn param_along(A: Point, B: Point, P: Point) -> f64 {
+    (P - A).dot(B - A) / A.distance_sq(B)
+}
+
+/// The cross ratio `(A, B; C, D)` of four collinear Points, i.e. `(AC / BC) / (AD / BD)` with
+/// signed lengths.
+/// If `A` and `B` overlap return `OverlappingPoint` error. If the Points are not collinear
+/// return `CollinearPoints` error. If `C` is `B` or `D` is `A` the ratio is infinite, and
+/// return `Infinity` error.
+pub fn cross_ratio(A: Point, B: Point, C: Point, D: Point) -> Result<f64> {
+    if A == B {
+        return Err(CalcException::OverlappingPoint);
+    }
+    if orientation(A, B, C) != 0 || orientation(A, B, D) != 0 {
+        return Err(CalcException::CollinearPoints);
+    }
+    let (c, d) = (param_along(A, B, C), param_along(A, B, D));
+    let q = (c - 1.0) * d;
+    if q.abs() < EPSILON {
+        return Err(CalcException::Infinity);
+    }
+    Ok(c * (d - 1.0) / q)
+}
+
+/// The harmonic conjugate of `C` with respect to `A`, `B`, i.e. the Point `D` with
+/// `(A, B; C, D) = -1`.
+/// If `A` and `B` overlap return `OverlappingPoint` error. If `C` is not on `AB` return
+/// `CollinearPoints` error. If `C` is the midpoint of `AB` the conjugate is at infinity,
+/// and return `Infinity` error.
+pub fn harmonic_conjugate(A: Point, B: Point, C: Point) -> Result<Point> {
+    if A == B {
+        return Err(CalcException::OverlappingPoint);
+    }
+    if orientation(A, B, C) != 0 {
+        return Err(CalcException::CollinearPoints);
+    }
+    let c = param_along(A, B, C);
+    let q = 2.0 * c - 1.0;
+    if q.abs() < EPSILON {
+        return Err(CalcException::Infinity);
+    }
+    Ok(A + (B - A) * (c / q))
+}
+
+/// Put a pair of intersections in canonical order, by `x` and then by `y`, with `x` within
+/// `EPSILON` counted as equal.
+#[inline]
+fn ordered(P: Point, Q: Point) -> (Point, Point) {
+    let swap = if aprx_eq(P.x, Q.x) {
+        P.y > Q.y
+    } else {
+        P.x > Q.x
+    };
+    if swap {
+        (Q, P)
+    } else {
+        (P, Q)
+    }
+}
+
+/// Compare two Points lexicographically, first by `x` then by `y`.
+/// This is an exact comparison, for sorting purposes only.
+#[inline]
+pub fn cmp_lex(A: &Point, B: &Point) -> std::cmp::Ordering {
+    A.x.total_cmp(&B.x).then(A.y.total_cmp(&B.y))
+}
+
+impl<T: Scalar> Point<T> {
+    /// The dot product, treating Points as vectors.
+    #[inline]
+    pub fn dot(self, P: Point<T>) -> T {
+        self.x * P.x + self.y * P.y
+    }
+    /// The cross product, treating Points as vectors.
+    /// This is the z-component of the 3D cross product, positive when `P` is
+    /// counterclockwise from `self`.
+    #[inline]
+    pub fn cross(self, P: Point<T>) -> T {
+        self.x * P.y - self.y * P.x
+    }
+    /// The square of the length of the vector.
+    #[inline]
+    pub fn norm_sq(self) -> T {
+        self.dot(self)
+    }
+    /// The length of the vector.
+    #[inline]
+    pub fn norm(self) -> T {
+        self.norm_sq().sqrt()
+    }
+}
+
 impl Point {
+    /// Construct a Point of `f64` coordinates. For other `Scalar` types use a struct literal
+    /// or `From<(T, T)>`, so that `Point::new(1.0, 2.0)` is never ambiguous.
     #[inline]
     pub fn new(x: f64, y: f64) -> Self {
         Point { x, y }
     }
-}
-
-impl Line {
-    /// Construct new Line from coefficients: `ax + by + c = 0`.
-    /// `a` and `b` cannot be both zero.
+    /// If two Points are equal under a given tolerance.
     #[inline]
-    pub fn from_coeff(a: f64, b: f64, c: f64) -> Result<Line> {
-        if a == 0.0 && b == 0.0 {
-            return Err(CalcException::ZeroCoefficient);
+    pub fn approx_eq_within(self, P: Point, tol: Tolerance) -> bool {
+        tol.equal(self.x, P.x) && tol.equal(self.y, P.y)
+    }
+    /// Linear interpolation, `self * (1 - t) + P * t`.
+    #[inline]
+    pub fn lerp(self, P: Point, t: f64) -> Point {
+        self * (1.0 - t) + P * t
+    }
+    /// The component-wise minimum of two Points.
+    #[inline]
+    pub fn min(self, P: Point) -> Point {
+        Point {
+            x: self.x.min(P.x),
+            y: self.y.min(P.y),
         }
-        Ok(Line { a, b, c })
     }
+    /// The component-wise maximum of two Points.
+    #[inline]
+    pub fn max(self, P: Point) -> Point {
+        Point {
+            x: self.x.max(P.x),
+            y: self.y.max(P.y),
+        }
+    }
+    /// Clamp a Point component-wise into the box between `lo` and `hi`.
+    #[inline]
+    pub fn clamp(self, lo: Point, hi: Point) -> Point {
+        self.max(lo).min(hi)
+    }
+}
+
+impl<T: Scalar> Line<T> {
     /// Construct new Line from two coefficients `ax + by + ?? = 0` and
     /// a given point that the line passes.
     #[inline]
-    pub fn from_slope_and_point(a: f64, b: f64, P: Point) -> Self {
+    pub fn from_slope_and_point(a: T, b: T, P: Point<T>) -> Self {
         Line {
             a,
             b,
@@ -50,7 +240,7 @@ impl Line {
     /// Construct new Line passing through two Points.
     /// If the two Points overlap return `OverlappingPoint` error.
     #[inline]
-    pub fn from_2p(A: Point, B: Point) -> Result<Self> {
+    pub fn from_2p(A: Point<T>, B: Point<T>) -> Result<Self> {
         if A == B {
             Err(CalcException::OverlappingPoint)
         } else {
@@ -63,12 +253,63 @@ impl Line {
     }
 }
 
-impl Circle {
+impl Line {
+    /// Construct new Line from coefficients: `ax + by + c = 0`.
+    /// `a` and `b` cannot be both zero.
+    #[inline]
+    pub fn from_coeff(a: f64, b: f64, c: f64) -> Result<Line> {
+        if a == 0.0 && b == 0.0 {
+            return Err(CalcException::ZeroCoefficient);
+        }
+        Ok(Line { a, b, c })
+    }
+    /// The unit vector along the Line, `(-b, a)` normalized.
+    #[inline]
+    pub fn direction(&self) -> Point {
+        Point {
+            x: -self.b,
+            y: self.a,
+        } / self.a.hypot(self.b)
+    }
+    /// The unit normal vector of the Line, `(a, b)` normalized.
+    #[inline]
+    pub fn normal(&self) -> Point {
+        Point {
+            x: self.a,
+            y: self.b,
+        } / self.a.hypot(self.b)
+    }
+    /// The angle of the Line with the `x`-axis, the one in `(-pi / 2, pi / 2]`.
+    #[inline]
+    pub fn inclination(&self) -> f64 {
+        if self.b == 0.0 {
+            FRAC_PI_2
+        } else {
+            (-self.a / self.b).atan()
+        }
+    }
+    /// The same Line scaled so that `a^2 + b^2 = 1`, with the canonical sign making `a`
+    /// positive, or `b` positive if `a` is zero.
+    #[inline]
+    pub fn normalized(self) -> Line {
+        let mut n = self.a.hypot(self.b);
+        if self.a < 0.0 || (self.a == 0.0 && self.b < 0.0) {
+            n = -n;
+        }
+        Line {
+            a: self.a / n,
+            b: self.b / n,
+            c: self.c / n,
+        }
+    }
+}
+
+impl<T: Scalar> Circle<T> {
     /// Construct a Circle with center `o` and radius `r`.
     /// If the radius given is nonpositive return `NonpositiveRadius` error.
     #[inline]
-    pub fn from_center_radius(O: Point, R: f64) -> Result<Self> {
-        if R <= 0.0 {
+    pub fn from_center_radius(O: Point<T>, R: T) -> Result<Self> {
+        if R <= T::ZERO {
             Err(CalcException::NonpositiveRadius)
         } else {
             Ok(Circle { O, r: R })
@@ -76,23 +317,155 @@ impl Circle {
     }
     /// Construct a Circle with center `o` and a point it passes through `a`.
     #[inline]
-    pub fn from_center_point(O: Point, A: Point) -> Result<Self> {
+    pub fn from_center_point(O: Point<T>, A: Point<T>) -> Result<Self> {
         if O == A {
             Err(CalcException::OverlappingPoint)
         } else {
             Ok(Circle {
                 O,
-                r: O.distance(A),
+                r: (A - O).norm(),
             })
         }
     }
+}
+
+impl Circle {
     /// Construct a Circle passing through three Points.
-    /// If any two of them overlap return `OverlappingPoint` error.
+    /// If any two of them overlap return `OverlappingPoint` error, and if they are
+    /// collinear return `CollinearPoints` error.
     pub fn from_3p(A: Point, B: Point, C: Point) -> Result<Self> {
+        if A == B || B == C || C == A {
+            return Err(CalcException::OverlappingPoint);
+        }
+        if orientation(A, B, C) == 0 {
+            return Err(CalcException::CollinearPoints);
+        }
         let O = perp_bisect(A, B)?.inter(perp_bisect(B, C)?)?;
         let r = O.distance(A);
         Ok(Circle { O, r })
     }
+    /// The area of the Circle.
+    #[inline]
+    pub fn area(&self) -> f64 {
+        PI * self.r * self.r
+    }
+    /// The circumference of the Circle.
+    #[inline]
+    pub fn circumference(&self) -> f64 {
+        2.0 * PI * self.r
+    }
+    /// The antipode of a Point on the Circle, i.e. its reflection through the center.
+    #[inline]
+    pub fn antipode(&self, P: Point) -> Point {
+        self.O * 2.0 - P
+    }
+    /// The midpoint of an arc `PQ` of the Circle, the major one if `major` is `true`.
+    /// If `PQ` is a diameter the two arcs are equal, and the midpoint of the counterclockwise
+    /// arc from `P` to `Q` is the minor one. If `P` and `Q` overlap the minor arc is the
+    /// point itself. If `P` or `Q` is not on the Circle return `NoIntersection` error.
+    pub fn arc_midpoint(&self, P: Point, Q: Point, major: bool) -> Result<Point> {
+        let on = |A: Point| (A.distance(self.O) - self.r).abs() < EPSILON * self.r.max(1.0);
+        if !on(P) || !on(Q) {
+            return Err(CalcException::NoIntersection);
+        }
+        let M = (P + Q) / 2.0 - self.O;
+        let D = if M.norm() < EPSILON {
+            let u = P - self.O;
+            Point { x: -u.y, y: u.x } / u.norm()
+        } else {
+            M / M.norm()
+        };
+        let D = if major { D * -1.0 } else { D };
+        Ok(self.O + D * self.r)
+    }
+    /// The bounding box of a Circle.
+    #[inline]
+    pub fn bounding_box(&self) -> AABB {
+        let d = Point {
+            x: self.r,
+            y: self.r,
+        };
+        AABB {
+            min: self.O - d,
+            max: self.O + d,
+        }
+    }
+}
+
+impl Segment {
+    /// Construct a Segment between two Points.
+    /// If the two Points overlap return `OverlappingPoint` error.
+    #[inline]
+    pub fn from_2p(A: Point, B: Point) -> Result<Self> {
+        if A == B {
+            Err(CalcException::OverlappingPoint)
+        } else {
+            Ok(Segment { A, B })
+        }
+    }
+    /// The length of the Segment.
+    #[inline]
+    pub fn length(&self) -> f64 {
+        self.A.distance(self.B)
+    }
+    /// The squared length of the Segment.
+    #[inline]
+    pub fn length_sq(&self) -> f64 {
+        self.A.distance_sq(self.B)
+    }
+    /// The Point of the Segment nearest to `P`. The projection parameter is clamped to
+    /// `[0, 1]`, so this is an endpoint when the foot of the perpendicular falls outside.
+    pub fn closest_point(&self, P: Point) -> Point {
+        let d = self.B - self.A;
+        let n = d.norm_sq();
+        if n == 0.0 {
+            return self.A;
+        }
+        self.A + d * ((P - self.A).dot(d) / n).clamp(0.0, 1.0)
+    }
+    /// The supporting Line of the Segment.
+    #[inline]
+    pub fn to_line(self) -> Line {
+        let Segment { A, B } = self;
+        Line {
+            a: A.y - B.y,
+            b: B.x - A.x,
+            c: A.x * B.y - A.y * B.x,
+        }
+    }
+}
+
+/// The bounding box of a set of Points. If there are no Points return `None`.
+pub fn bounding_box(points: &[Point]) -> Option<AABB> {
+    let (&P, rest) = points.split_first()?;
+    Some(rest.iter().fold(AABB { min: P, max: P }, |b, &Q| AABB {
+        min: b.min.min(Q),
+        max: b.max.max(Q),
+    }))
+}
+
+impl AABB {
+    /// Test if a Point is in the box, boundary included.
+    #[inline]
+    pub fn contains(&self, P: Point) -> bool {
+        self.min.x <= P.x && P.x <= self.max.x && self.min.y <= P.y && P.y <= self.max.y
+    }
+    /// Test if two boxes overlap, touching included.
+    #[inline]
+    pub fn intersects(&self, other: &AABB) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+    /// The smallest box containing both boxes.
+    #[inline]
+    pub fn union(self, other: AABB) -> AABB {
+        AABB {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
 }
 
 /// A trait for computing distance.
@@ -127,6 +500,15 @@ impl Distance<Line> for Point {
     }
 }
 
+impl Distance<Segment> for Point {
+    /// The distance to the nearest Point of the Segment, unlike `Distance<Line>` which uses
+    /// the whole supporting Line.
+    #[inline]
+    fn distance_sq(self, s: Segment) -> f64 {
+        self.distance_sq(s.closest_point(self))
+    }
+}
+
 impl Distance<Line> for Line {
     fn distance_sq(self, l: Line) -> f64 {
         if !is_parallel(self, l) {
@@ -138,6 +520,40 @@ impl Distance<Line> for Line {
     }
 }
 
+impl Distance<Circle> for Circle {
+    /// The minimum gap between the boundaries of two Circles.
+    /// This is `0.0` when the two Circles intersect.
+    #[inline]
+    fn distance_sq(self, c: Circle) -> f64 {
+        let d = self.distance(c);
+        d * d
+    }
+    fn distance(self, c: Circle) -> f64 {
+        let d = self.O.distance(c.O);
+        if d > self.r + c.r {
+            d - self.r - c.r
+        } else if d < (self.r - c.r).abs() {
+            (self.r - c.r).abs() - d
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Distance<Line> for Circle {
+    /// The minimum gap between a Circle and a Line.
+    /// This is `0.0` when the Line cuts or touches the Circle.
+    #[inline]
+    fn distance_sq(self, l: Line) -> f64 {
+        let d = self.distance(l);
+        d * d
+    }
+    #[inline]
+    fn distance(self, l: Line) -> f64 {
+        (self.O.distance(l) - self.r).max(0.0)
+    }
+}
+
 /// The angle defined by three points, the one in `[0, pi / 2]`.
 pub fn angle(A: Point, O: Point, B: Point) -> Result<f64> {
     if A == O || B == O {
@@ -160,63 +576,138 @@ pub fn angle_between(l: Line, k: Line) -> f64 {
     p.abs().acos()
 }
 
-impl std::cmp::PartialEq for Point {
+/// The signed angle from `OA` to `OB`, the one in `(-pi, pi]`, positive if
+/// counterclockwise.
+pub fn signed_angle(A: Point, O: Point, B: Point) -> Result<f64> {
+    if A == O || B == O {
+        return Err(CalcException::OverlappingPoint);
+    }
+    let (u, v) = (A - O, B - O);
+    Ok(u.cross(v).atan2(u.dot(v)))
+}
+
+/// The directed angle from `l` to `k`, i.e. the angle rotating `l` counterclockwise to be
+/// parallel to `k`, the one in `(-pi / 2, pi / 2]`.
+pub fn directed_angle_between(l: Line, k: Line) -> f64 {
+    let u = Point { x: -l.b, y: l.a };
+    let v = Point { x: -k.b, y: k.a };
+    let t = u.cross(v).atan2(u.dot(v));
+    if t > FRAC_PI_2 {
+        t - PI
+    } else if t <= -FRAC_PI_2 {
+        t + PI
+    } else {
+        t
+    }
+}
+
+impl<T: Scalar> std::cmp::PartialEq for Point<T> {
     /// If two Points are _approximately_ equal.
     /// We say _approximately_ because there could be error.
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        aprx_eq(self.x, other.x) && aprx_eq(self.y, other.y)
+        self.approx_eq(other, T::EPSILON)
     }
 }
 
-impl std::cmp::PartialEq for Line {
+impl<T: Scalar> std::cmp::PartialEq for Line<T> {
     /// If two Lines _approximately_ overlaps.
     /// We say _approximately_ because there could be error.
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        is_parallel(*self, *other) && aprx_eq(self.c, other.c)
+        self.approx_eq(other, T::EPSILON)
     }
 }
 
-impl std::cmp::PartialEq for Circle {
+impl<T: Scalar> std::cmp::PartialEq for Circle<T> {
     /// If two Circles _approximately_ overlaps.
     /// We say _approximately_ because there could be error.
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.O == other.O && aprx_eq(self.r, other.r)
+        self.approx_eq(other, T::EPSILON)
     }
 }
 
-/// A trait for constructing intersections.
-pub trait Intersect<T> {
+impl std::cmp::PartialEq for Segment {
+    /// If two Segments _approximately_ overlaps, regardless of the order of endpoints.
+    /// We say _approximately_ because there could be error.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        (self.A == other.A && self.B == other.B) || (self.A == other.B && self.B == other.A)
+    }
+}
+
+impl std::cmp::PartialEq for AABB {
+    /// If two boxes _approximately_ overlaps.
+    /// We say _approximately_ because there could be error.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.min == other.min && self.max == other.max
+    }
+}
+
+/// A trait for constructing intersections, with coordinates of type `S`.
+pub trait Intersect<T, S = f64> {
     /// The result of intersection.
     /// This is generally `Option<Point>`, or a tuple of `Option<Point>`.
     /// Use `Option` because there might be no intersections.
     type InterResult;
     /// Intersection.
+    /// When this gives a pair of Points they are in canonical order, by `x` and then by
+    /// `y`, with `x` within `EPSILON` counted as equal, so the order does not depend on
+    /// how the objects are represented.
     fn inter(self, obj: T) -> Result<Self::InterResult>;
     /// Intersection _with a common point given_.
     /// This can simplify calculation (using Vieta's theorem).
     /// **Do notice that** this common point will _directly_ affect the result.
     /// If a wrong common point is given the result will be _totally wrong_.
     /// The given common point is always the last element of the tuple.
-    fn inter_common(self, _: T, common: Point) -> Result<Self::InterResult>;
+    fn inter_common(self, _: T, common: Point<S>) -> Result<Self::InterResult>;
+    /// Intersection with a common point given, like `inter_common`, but first check that the
+    /// common point is on both objects.
+    /// If it is not return `NoIntersection` error.
+    fn inter_common_checked(self, obj: T, common: Point<S>) -> Result<Self::InterResult>
+    where
+        Self: TestThrough<Point<S>> + Copy + Sized,
+        T: TestThrough<Point<S>> + Copy,
+        S: Copy,
+    {
+        if self.is_through(common) && obj.is_through(common) {
+            self.inter_common(obj, common)
+        } else {
+            Err(CalcException::NoIntersection)
+        }
+    }
 }
 
-impl Intersect<Line> for Line {
-    type InterResult = Point;
-    fn inter(self, obj: Line) -> Result<Self::InterResult> {
-        if is_parallel(self, obj) {
-            Err(CalcException::NoIntersection)
+impl<T: Scalar> Intersect<Line<T>, T> for Line<T> {
+    type InterResult = Point<T>;
+    /// The common Point of two Lines.
+    /// If the Lines are parallel return `NoIntersection` error, and if they coincide
+    /// return `CoincidentLines` error.
+    fn inter(self, obj: Line<T>) -> Result<Self::InterResult> {
+        let d = self.a * obj.b - obj.a * self.b;
+        if d.abs() < T::EPSILON {
+            // The foot of the perpendicular from the origin to `self`.
+            let n = self.a * self.a + self.b * self.b;
+            let P = Point {
+                x: -self.a * self.c / n,
+                y: -self.b * self.c / n,
+            };
+            let z = obj.a * P.x + obj.b * P.y + obj.c;
+            if z.abs() < T::EPSILON * (obj.a * obj.a + obj.b * obj.b).sqrt() {
+                Err(CalcException::CoincidentLines)
+            } else {
+                Err(CalcException::NoIntersection)
+            }
         } else {
-            let a = self.b * obj.c - obj.b * self.c;
-            let b = self.c * obj.a - obj.c * self.a;
-            let d = self.a * obj.b - obj.a * self.b;
-            Ok(Point { x: a / d, y: b / d })
+            let x = self.b * obj.c - obj.b * self.c;
+            let y = self.c * obj.a - obj.c * self.a;
+            Ok(Point { x: x / d, y: y / d })
         }
     }
     #[inline]
-    fn inter_common(self, _: Line, common: Point) -> Result<Self::InterResult> {
+    fn inter_common(self, _: Line<T>, common: Point<T>) -> Result<Self::InterResult> {
         Ok(common)
     }
 }
@@ -237,7 +728,7 @@ impl Intersect<Circle> for Line {
             let disc = disc.sqrt();
             let y1 = (-yb + disc) / ya / 2.0;
             let y2 = (-yb - disc) / ya / 2.0;
-            Ok((
+            Ok(ordered(
                 Point {
                     x: -(b * y1 + c) / a,
                     y: y1,
@@ -250,7 +741,7 @@ impl Intersect<Circle> for Line {
         } else {
             let xa = b * b;
             let xb = -2.0 * b * b * O.x;
-            let xc = b * b * (O.x * O.x - r * r) + (b * O.x + c) * (b * O.x + c);
+            let xc = b * b * (O.x * O.x - r * r) + (b * O.y + c) * (b * O.y + c);
             let disc = xb * xb - 4.0 * xa * xc;
             if disc < 0.0 {
                 return Err(CalcException::NoIntersection);
@@ -258,7 +749,10 @@ impl Intersect<Circle> for Line {
             let disc = disc.sqrt();
             let x1 = (-xb + disc) / xa / 2.0;
             let x2 = (-xb - disc) / xa / 2.0;
-            Ok((Point { x: x1, y: -c / b }, Point { x: x2, y: -c / b }))
+            Ok(ordered(
+                Point { x: x1, y: -c / b },
+                Point { x: x2, y: -c / b },
+            ))
         }
     }
     fn inter_common(self, obj: Circle, common: Point) -> Result<Self::InterResult> {
@@ -296,7 +790,132 @@ impl Intersect<Line> for Circle {
     }
 }
 
+impl Intersect<Segment> for Segment {
+    type InterResult = Point;
+    /// The crossing point of two Segments, endpoints included within `EPSILON`.
+    /// If the Segments do not meet, or are parallel, return `NoIntersection` error.
+    fn inter(self, obj: Segment) -> Result<Self::InterResult> {
+        let (u, v) = (self.B - self.A, obj.B - obj.A);
+        let d = u.cross(v);
+        if d.abs() < EPSILON {
+            return Err(CalcException::NoIntersection);
+        }
+        let w = obj.A - self.A;
+        let (s, t) = (w.cross(v) / d, w.cross(u) / d);
+        let range = -EPSILON..=1.0 + EPSILON;
+        if range.contains(&s) && range.contains(&t) {
+            Ok(self.A + u * s)
+        } else {
+            Err(CalcException::NoIntersection)
+        }
+    }
+    #[inline]
+    fn inter_common(self, _: Segment, common: Point) -> Result<Self::InterResult> {
+        Ok(common)
+    }
+}
+
+/// The intersections of a Line with the Circle about `O` with squared radius `r2`, through
+/// the foot of the perpendicular from `O`, in the canonical order of `inter`.
+#[inline]
+fn chord(l: Line, O: Point, r2: f64) -> Result<(Point, Point)> {
+    let n = l.a * l.a + l.b * l.b;
+    if n == 0.0 {
+        return Err(CalcException::NoIntersection);
+    }
+    let z = l.a * O.x + l.b * O.y + l.c;
+    let h = r2 - z * z / n;
+    if h < 0.0 {
+        return Err(CalcException::NoIntersection);
+    }
+    let F = O - Point { x: l.a, y: l.b } * (z / n);
+    let v = Point { x: -l.b, y: l.a } * (h / n).sqrt();
+    Ok(ordered(F + v, F - v))
+}
+
+/// Intersect many Lines with one Circle, computing the invariants of the Circle once.
+/// Each element is what `l.inter(c)` gives, so one failure does not abort the batch.
+pub fn inter_batch(lines: &[Line], c: Circle) -> Vec<Result<(Point, Point)>> {
+    let r2 = c.r * c.r;
+    lines.iter().map(|&l| chord(l, c.O, r2)).collect()
+}
+
+/// Intersect many Circles with one Circle, computing the invariants of the Circle once.
+/// Each element is what `d.inter(c)` gives, so one failure does not abort the batch.
+/// Concentric Circles give `NoIntersection` error.
+pub fn inter_batch_circles(circles: &[Circle], c: Circle) -> Vec<Result<(Point, Point)>> {
+    let r2 = c.r * c.r;
+    let f = c.O.norm_sq() - r2;
+    circles
+        .iter()
+        .map(|d| {
+            let axis = Line {
+                a: 2.0 * (c.O.x - d.O.x),
+                b: 2.0 * (c.O.y - d.O.y),
+                c: d.O.norm_sq() - d.r * d.r - f,
+            };
+            chord(axis, c.O, r2)
+        })
+        .collect()
+}
+
+/// The relative position of two Circles.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircleRelation {
+    /// Each Circle is outside the other
+    Separate,
+    /// Each Circle is outside the other, touching at one point
+    ExternallyTangent,
+    /// The Circles meet at two points
+    Intersecting,
+    /// One Circle is inside the other, touching at one point
+    InternallyTangent,
+    /// One Circle is strictly inside the other
+    Contained,
+    /// The Circles have the same center
+    Concentric,
+}
+
+/// Classify the relative position of two Circles, by comparing the distance of centers
+/// with the sum and the difference of radii under `EPSILON`.
+/// Circles with the same center are `Concentric` regardless of radii.
+pub fn circle_relation(c: Circle, d: Circle) -> CircleRelation {
+    let D = c.O.distance(d.O);
+    let (s, t) = (c.r + d.r, (c.r - d.r).abs());
+    if D < EPSILON {
+        CircleRelation::Concentric
+    } else if aprx_eq(D, s) {
+        CircleRelation::ExternallyTangent
+    } else if D > s {
+        CircleRelation::Separate
+    } else if aprx_eq(D, t) {
+        CircleRelation::InternallyTangent
+    } else if D < t {
+        CircleRelation::Contained
+    } else {
+        CircleRelation::Intersecting
+    }
+}
+
+/// The power `|PO|^2 - r^2` of a Point with respect to a Circle, negative inside the
+/// Circle, zero on it and positive outside.
+#[inline]
+pub fn power(P: Point, c: Circle) -> f64 {
+    P.distance_sq(c.O) - c.r * c.r
+}
+
+/// The inversive distance `(d^2 - r1^2 - r2^2) / (2 r1 r2)` of two Circles, where `d` is the
+/// distance of centers. It is invariant under inversion: it is `1` for externally tangent
+/// Circles, `0` for orthogonal ones, `-1` for internally tangent ones, and below `-1` for
+/// nested ones.
+#[inline]
+pub fn inversive_distance(c: Circle, d: Circle) -> f64 {
+    (c.O.distance_sq(d.O) - c.r * c.r - d.r * d.r) / (2.0 * c.r * d.r)
+}
+
 /// The radical axis of two Circles.
+/// For concentric Circles both `a` and `b` are zero, which is not a valid Line.
 pub fn radical_axis(c: Circle, d: Circle) -> Line {
     let O = c.O;
     let P = d.O;
@@ -315,18 +934,28 @@ pub fn radical_axis(c: Circle, d: Circle) -> Line {
 
 impl Intersect<Circle> for Circle {
     type InterResult = (Point, Point);
+    /// Concentric Circles give `NoIntersection` error.
     #[inline]
     fn inter(self, obj: Circle) -> Result<Self::InterResult> {
-        radical_axis(self, obj).inter(obj)
+        let l = radical_axis(self, obj);
+        if l.a == 0.0 && l.b == 0.0 {
+            return Err(CalcException::NoIntersection);
+        }
+        l.inter(obj)
     }
     #[inline]
     fn inter_common(self, obj: Circle, common: Point) -> Result<Self::InterResult> {
-        radical_axis(self, obj).inter_common(obj, common)
+        let l = radical_axis(self, obj);
+        if l.a == 0.0 && l.b == 0.0 {
+            return Err(CalcException::NoIntersection);
+        }
+        l.inter_common(obj, common)
     }
 }
 
 /// A trait for testing whether an object passes through an instance of `T`.
 /// Provides the `is_through` method.
+#[allow(clippy::wrong_self_convention)]
 pub trait TestThrough<T> {
     fn is_through(self, P: T) -> bool;
 }
@@ -346,3 +975,65 @@ impl TestThrough<Point> for Circle {
         aprx_eq(self.r * self.r, self.O.distance_sq(p))
     }
 }
+
+impl Ellipse {
+    /// The value of `(u/a)^2 + (v/b)^2` at a Point, where `(u, v)` are its coordinates
+    /// along the axes of the Ellipse.
+    #[inline]
+    fn level(self, P: Point) -> f64 {
+        let (s, c) = self.angle.sin_cos();
+        let D = P - self.O;
+        let u = D.x * c + D.y * s;
+        let v = D.y * c - D.x * s;
+        (u / self.a).powi(2) + (v / self.b).powi(2)
+    }
+}
+
+impl TestThrough<Point> for Ellipse {
+    /// Test if the Ellipse is through a Point.
+    #[inline]
+    fn is_through(self, P: Point) -> bool {
+        aprx_eq(self.level(P), 1.0)
+    }
+}
+
+impl TestThrough<Point> for Conic {
+    /// Test if the Conic is through a Point.
+    #[inline]
+    fn is_through(self, P: Point) -> bool {
+        let Point { x, y } = P;
+        let z = self.a * x * x + self.b * x * y + self.c * y * y + self.d * x + self.e * y + self.f;
+        aprx_eq(z, 0.0)
+    }
+}
+
+/// A trait for testing whether an object strictly contains an instance of `T`.
+/// Provides the `contains` method, and the `on_boundary` method for the boundary case.
+pub trait Contains<T>: TestThrough<T>
+where
+    Self: Sized,
+{
+    /// Test if `P` is strictly inside the object.
+    fn contains(self, P: T) -> bool;
+    /// Test if `P` is on the boundary of the object.
+    #[inline]
+    fn on_boundary(self, P: T) -> bool {
+        self.is_through(P)
+    }
+}
+
+impl Contains<Point> for Circle {
+    /// Test if the Point is strictly inside the Circle.
+    #[inline]
+    fn contains(self, P: Point) -> bool {
+        self.O.distance_sq(P) < self.r * self.r - EPSILON
+    }
+}
+
+impl Contains<Point> for Ellipse {
+    /// Test if the Point is strictly inside the Ellipse.
+    #[inline]
+    fn contains(self, P: Point) -> bool {
+        self.level(P) < 1.0 - EPSILON
+    }
+}
@@ -0,0 +1,168 @@
+#![allow(non_snake_case)]
+
+use crate::objects::{Circle, Line, Point};
+
+use super::{basic::Distance, constants::EPSILON, scalar::Scalar};
+
+/// Approximate equality under a chosen epsilon. The approximate `PartialEq` of the objects
+/// is `approx_eq` with the `EPSILON` of their `Scalar`.
+pub trait ApproxEq {
+    /// The type of the epsilon.
+    type Tol;
+    fn approx_eq(&self, other: &Self, eps: Self::Tol) -> bool;
+}
+
+impl<T: Scalar> ApproxEq for T {
+    type Tol = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, eps: T) -> bool {
+        (*self - *other).abs() < eps
+    }
+}
+
+impl<T: Scalar> ApproxEq for Point<T> {
+    type Tol = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, eps: T) -> bool {
+        self.x.approx_eq(&other.x, eps) && self.y.approx_eq(&other.y, eps)
+    }
+}
+
+impl<T: Scalar> ApproxEq for Line<T> {
+    type Tol = T;
+    /// The Lines are parallel, with `c` equal. See `PartialEq` for `Line`.
+    #[inline]
+    fn approx_eq(&self, other: &Self, eps: T) -> bool {
+        (self.a * other.b).approx_eq(&(self.b * other.a), eps) && self.c.approx_eq(&other.c, eps)
+    }
+}
+
+impl<T: Scalar> ApproxEq for Circle<T> {
+    type Tol = T;
+    #[inline]
+    fn approx_eq(&self, other: &Self, eps: T) -> bool {
+        self.O.approx_eq(&other.O, eps) && self.r.approx_eq(&other.r, eps)
+    }
+}
+
+/// A collection of the fuzzy predicates, all using a single chosen epsilon instead of
+/// the global `EPSILON`.
+/// Each predicate uses the same formula as its counterpart in `basic`, so the default
+/// `Comparator` agrees with `PartialEq`, `is_through` and `is_parallel`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparator {
+    pub eps: f64,
+}
+
+impl Default for Comparator {
+    #[inline]
+    fn default() -> Self {
+        Comparator { eps: EPSILON }
+    }
+}
+
+impl Comparator {
+    #[inline]
+    pub fn new(eps: f64) -> Self {
+        Comparator { eps }
+    }
+    /// Test if two floats are equal.
+    #[inline]
+    pub fn equal(&self, a: f64, b: f64) -> bool {
+        a.approx_eq(&b, self.eps)
+    }
+    /// Test if two Points are equal.
+    #[inline]
+    pub fn points_eq(&self, P: Point, Q: Point) -> bool {
+        P.approx_eq(&Q, self.eps)
+    }
+    /// Test if two Lines overlap.
+    #[inline]
+    pub fn lines_eq(&self, l: Line, k: Line) -> bool {
+        l.approx_eq(&k, self.eps)
+    }
+    /// Test if two Circles overlap.
+    #[inline]
+    pub fn circles_eq(&self, c: Circle, d: Circle) -> bool {
+        c.approx_eq(&d, self.eps)
+    }
+    /// Test if a Point is on a Line.
+    #[inline]
+    pub fn on_line(&self, P: Point, l: Line) -> bool {
+        self.equal(l.a * P.x + l.b * P.y + l.c, 0.0)
+    }
+    /// Test if a Point is on a Circle.
+    #[inline]
+    pub fn on_circle(&self, P: Point, c: Circle) -> bool {
+        self.equal(c.r * c.r, c.O.distance_sq(P))
+    }
+    /// Test if two Lines are parallel.
+    #[inline]
+    pub fn is_parallel(&self, l: Line, k: Line) -> bool {
+        self.equal(l.a * k.b, l.b * k.a)
+    }
+    /// Test if three Points are collinear.
+    #[inline]
+    pub fn collinear(&self, A: Point, B: Point, C: Point) -> bool {
+        self.equal((B - A).cross(C - A), 0.0)
+    }
+}
+
+/// The residual between two objects, the largest deviation among the quantities their
+/// approximate `PartialEq` compares. Used by `geo_assert_eq!`.
+pub trait Residual {
+    fn residual(&self, other: &Self) -> f64;
+}
+
+impl Residual for Point {
+    #[inline]
+    fn residual(&self, other: &Self) -> f64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl Residual for Line {
+    #[inline]
+    fn residual(&self, other: &Self) -> f64 {
+        (self.a * other.b - self.b * other.a)
+            .abs()
+            .max((self.c - other.c).abs())
+    }
+}
+
+impl Residual for Circle {
+    #[inline]
+    fn residual(&self, other: &Self) -> f64 {
+        self.O.residual(&other.O).max((self.r - other.r).abs())
+    }
+}
+
+/// Assert that two `Point`s, `Line`s or `Circle`s are approximately equal, with an
+/// optional epsilon defaulting to `EPSILON`.
+/// On failure both operands and their residual are printed.
+///
+/// ```
+/// use metric_rs::{geo_assert_eq, objects::Point};
+///
+/// geo_assert_eq!(Point::new(1.0, 2.0), Point::new(1.0, 2.0 + 1e-12));
+/// geo_assert_eq!(Point::new(1.0, 2.0), Point::new(1.0, 2.001), 1e-2);
+/// ```
+#[macro_export]
+macro_rules! geo_assert_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::geo_assert_eq!($left, $right, $crate::calc::constants::EPSILON)
+    };
+    ($left:expr, $right:expr, $eps:expr $(,)?) => {
+        match (&$left, &$right, $eps) {
+            (left, right, eps) => {
+                let residual: f64 = $crate::calc::compare::Residual::residual(left, right);
+                if !(residual < eps) {
+                    panic!(
+                        "assertion `left ≈ right` failed\n    left: {}\n   right: {}\nresidual: {:e} (eps = {:e})",
+                        left, right, residual, eps
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,33 @@
+//! Arithmetic on Points as complex numbers `x + iy`.
+
+#![allow(non_snake_case)]
+
+use crate::objects::Point;
+
+/// Complex multiplication.
+#[inline]
+pub(crate) fn cmul(P: Point, Q: Point) -> Point {
+    Point {
+        x: P.x * Q.x - P.y * Q.y,
+        y: P.x * Q.y + P.y * Q.x,
+    }
+}
+
+/// Complex division. `Q` should not be zero.
+#[inline]
+pub(crate) fn cdiv(P: Point, Q: Point) -> Point {
+    Point {
+        x: P.x * Q.x + P.y * Q.y,
+        y: P.y * Q.x - P.x * Q.y,
+    } / Q.norm_sq()
+}
+
+/// Principal complex square root.
+#[inline]
+pub(crate) fn csqrt(P: Point) -> Point {
+    let r = P.norm();
+    Point {
+        x: ((r + P.x) / 2.0).sqrt(),
+        y: ((r - P.x) / 2.0).sqrt().copysign(P.y),
+    }
+}
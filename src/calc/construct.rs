@@ -1,23 +1,30 @@
 #![allow(non_snake_case)]
 
-use crate::objects::{Circle, Line, Point};
+use crate::objects::{Circle, Line, Point, Polygon};
 
 use super::{
-    basic::{Intersect, TestThrough},
+    basic::{
+        circle_relation, inversive_distance, power, radical_axis, CircleRelation, Distance,
+        Intersect, TestThrough,
+    },
+    complex::{cmul, csqrt},
+    constants::EPSILON,
     exception::{CalcException, Result},
+    point_on::PointOn,
+    scalar::Scalar,
 };
 
 /// Construct midpoint.
 #[inline]
-pub fn midpoint(A: Point, B: Point) -> Point {
-    (A + B) / 2.0
+pub fn midpoint<T: Scalar>(A: Point<T>, B: Point<T>) -> Point<T> {
+    (A + B) / (T::ONE + T::ONE)
 }
 
 /// Construct center of polygon.
 pub fn center(poly: &Vec<Point>) -> Point {
     let mut s = Point { x: 0.0, y: 0.0 };
     for p in poly {
-        s = s + *p;
+        s += *p;
     }
     s / poly.len() as f64
 }
@@ -42,6 +49,29 @@ pub fn perp(A: Point, l: Line) -> Line {
     }
 }
 
+impl Line {
+    /// Construct the perpendicular line through a Point, same as `perp(P, self)`.
+    /// This allows chaining constructions fluently:
+    ///
+    /// ```
+    /// use metric_rs::{calc::basic::Intersect, objects::{Line, Point}};
+    ///
+    /// let l = Line::from_2p(Point::new(0.0, 0.0), Point::new(1.0, 1.0)).unwrap();
+    /// let k = Line::from_2p(Point::new(0.0, 2.0), Point::new(1.0, 2.0)).unwrap();
+    /// let P = l.perpendicular_at(Point::new(1.0, 1.0)).inter(k).unwrap();
+    /// assert!(P == Point::new(0.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn perpendicular_at(&self, P: Point) -> Line {
+        perp(P, *self)
+    }
+    /// Construct the parallel line through a Point, same as `parallel(P, self)`.
+    #[inline]
+    pub fn parallel_at(&self, P: Point) -> Line {
+        parallel(P, *self)
+    }
+}
+
 /// Construct the projection of a Point on a Line.
 pub fn projection(A: Point, l: Line) -> Point {
     let Line { a, b, c } = l;
@@ -52,12 +82,34 @@ pub fn projection(A: Point, l: Line) -> Point {
     }
 }
 
+/// Returns the parameters of the intersections of a Line and a Circle, as the signed
+/// distances along `l` from the projection of `from` on `l`, measured in the direction
+/// `(-b, a)` of `ax + by + c = 0`. The smaller parameter comes first.
+pub fn line_circle_params(l: Line, c: Circle, from: Point) -> Result<(f64, f64)> {
+    let (P, Q) = l.inter(c)?;
+    let F = projection(from, l);
+    let d = l.direction();
+    let (s, t) = ((P - F).dot(d), (Q - F).dot(d));
+    Ok(if s <= t { (s, t) } else { (t, s) })
+}
+
 /// Construct the perpendicular bisector of two points.
 #[inline]
 pub fn perp_bisect(A: Point, B: Point) -> Result<Line> {
     Ok(perp(midpoint(A, B), Line::from_2p(A, B)?))
 }
 
+/// Construct the feet of the perpendiculars from the centers of two Circles to their
+/// radical axis, the first from `c` and the second from `d`.
+/// Since the radical axis is perpendicular to the line of centers, the two feet are
+/// where the radical axis meets the line of centers.
+/// The two Circles should not be concentric, otherwise there is no radical axis.
+#[inline]
+pub fn radical_axis_feet(c: Circle, d: Circle) -> (Point, Point) {
+    let l = radical_axis(c, d);
+    (projection(c.O, l), projection(d.O, l))
+}
+
 /// Constructs the two angle bisectors of two lines.
 pub fn angle_bisect(l: Line, k: Line) -> (Line, Line) {
     let Line { a, b, c } = l;
@@ -81,6 +133,9 @@ pub fn angle_bisect(l: Line, k: Line) -> (Line, Line) {
 }
 
 /// Constructs the two angle bisectors of an angle, interior first, exterior second.
+/// Both sides are built from `O` outwards, so their normals are the directions `OA`, `OB`
+/// rotated by the same right angle, and the order does not depend on the orientation of
+/// `A, O, B`.
 #[inline]
 pub fn angle_bisect_3p(A: Point, O: Point, B: Point) -> Result<(Line, Line)> {
     Ok(angle_bisect(Line::from_2p(O, A)?, Line::from_2p(O, B)?))
@@ -95,14 +150,38 @@ pub fn polar_line(A: Point, c: Circle) -> Result<Line> {
     Line::from_coeff(x0 - a, y0 - b, a * (a - x0) + b * (b - y0) - r * r)
 }
 
-/// Construct the tangent through a point.
+/// Construct the pole of a line w.r.t. a circle, the point whose polar line is `l`.
+/// If the line passes through the center the pole is at infinity, and return `Infinity`
+/// error.
+pub fn pole(l: Line, c: Circle) -> Result<Point> {
+    if l.is_through(c.O) {
+        return Err(CalcException::Infinity);
+    }
+    let n = Point { x: l.a, y: l.b };
+    Ok(c.O - n * (c.r * c.r / (l.c + n.dot(c.O))))
+}
+
+/// Construct the tangent through a point. If the point is on the circle both tangents are
+/// the same.
+/// If the point is inside the circle there are no tangents, and return `NoIntersection`
+/// error.
+///
+/// Otherwise the first tangent touches the circle at the tangent point counterclockwise
+/// from the line `AO` as seen from `A`, and the second at the clockwise one.
 #[inline]
 pub fn tangent(A: Point, c: Circle) -> Result<(Line, Line)> {
     if c.is_through(A) {
         let l = perp(A, Line::from_2p(A, c.O)?);
         Ok((l, l))
+    } else if power(A, c) < -EPSILON {
+        Err(CalcException::NoIntersection)
     } else {
         let (P, Q) = c.inter(polar_line(A, c)?)?;
+        let (P, Q) = if (c.O - A).cross(P - A) > 0.0 {
+            (P, Q)
+        } else {
+            (Q, P)
+        };
         Ok((Line::from_2p(A, P)?, Line::from_2p(A, Q)?))
     }
 }
@@ -137,3 +216,243 @@ pub fn inner_common_tangent(c: Circle, d: Circle) -> Result<(Line, Line)> {
     let (_, O) = homothety_center(c, d)?;
     tangent(O, c)
 }
+
+/// Construct all common tangents of two circles: none if one is inside the other (or
+/// they are concentric), one if they are internally tangent, the two outer tangents if
+/// they intersect, plus the tangent at the contact point if they are externally tangent,
+/// or plus the two inner tangents if they are separate. Outer tangents come first.
+/// Equal radii are handled, with parallel outer tangents.
+pub fn common_tangents(c: Circle, d: Circle) -> Vec<Line> {
+    let D = c.O.distance(d.O);
+    let u = (d.O - c.O) / D;
+    // A unit normal `n` with `n.(d.O - c.O) = s d.r - c.r`, so the tangent touches `d` on
+    // the same side as `c` if `s` is `1`, and the opposite side if `s` is `-1`.
+    let tangents = |s: f64, both: bool| {
+        let t = ((s * d.r - c.r) / D).clamp(-1.0, 1.0).acos();
+        let angles = if both { vec![t, -t] } else { vec![t] };
+        angles.into_iter().map(move |t| {
+            let (sin, cos) = t.sin_cos();
+            let n = Point {
+                x: u.x * cos - u.y * sin,
+                y: u.x * sin + u.y * cos,
+            };
+            Line {
+                a: n.x,
+                b: n.y,
+                c: c.r - n.dot(c.O),
+            }
+        })
+    };
+    match circle_relation(c, d) {
+        CircleRelation::Concentric | CircleRelation::Contained => vec![],
+        CircleRelation::InternallyTangent => tangents(1.0, false).collect(),
+        CircleRelation::Intersecting => tangents(1.0, true).collect(),
+        CircleRelation::ExternallyTangent => {
+            tangents(1.0, true).chain(tangents(-1.0, false)).collect()
+        }
+        CircleRelation::Separate => tangents(1.0, true).chain(tangents(-1.0, true)).collect(),
+    }
+}
+
+/// Construct the two circles of radius `r` tangent to a line at a point on it, the first on
+/// the side the normal `(a, b)` points to and the second on the other side.
+/// If `P` is not on `l` return `NoIntersection` error. If `r` is nonpositive return
+/// `NonpositiveRadius` error.
+pub fn circle_tangent_to_line_at(P: Point, l: Line, r: f64) -> Result<(Circle, Circle)> {
+    if !l.is_through(P) {
+        return Err(CalcException::NoIntersection);
+    }
+    let n = l.normal() * r;
+    Ok((
+        Circle::from_center_radius(P + n, r)?,
+        Circle::from_center_radius(P - n, r)?,
+    ))
+}
+
+/// Construct the circle through a point and tangent to a line at a given point on it.
+/// The center is the intersection of the perpendicular to `l` at `T` and the perpendicular
+/// bisector of `P` and `T`.
+/// If `T` is not on `l` return `NoIntersection` error. If `P` overlaps `T` return
+/// `OverlappingPoint` error, and if `P` is another point on `l` the center is at infinity,
+/// and return `Infinity` error.
+pub fn circle_through_tangent_to_line(P: Point, T: Point, l: Line) -> Result<Circle> {
+    if !l.is_through(T) {
+        return Err(CalcException::NoIntersection);
+    }
+    let m = perp_bisect(P, T)?;
+    if l.is_through(P) {
+        return Err(CalcException::Infinity);
+    }
+    let O = perp(T, l).inter(m)?;
+    Ok(Circle {
+        O,
+        r: O.distance(T),
+    })
+}
+
+/// Construct the two circles tangent to three mutually tangent circles, by the Descartes
+/// circle theorem. A circle enclosing the other two gets a negative curvature.
+/// The first result is the one with the larger curvature (the inner Soddy circle when the
+/// three are externally tangent), the second the other one (the outer Soddy circle).
+/// If two of the circles are not tangent return `NoIntersection` error. If a solution
+/// degenerates to a line (zero curvature) return `Infinity` error.
+pub fn descartes_fourth(c1: Circle, c2: Circle, c3: Circle) -> Result<(Circle, Circle)> {
+    let C = [c1, c2, c3];
+    let mut k = [0.0; 3];
+    for i in 0..3 {
+        k[i] = 1.0 / C[i].r;
+        for j in 0..3 {
+            if i == j {
+                continue;
+            }
+            match circle_relation(C[i], C[j]) {
+                CircleRelation::ExternallyTangent => (),
+                CircleRelation::InternallyTangent if C[i].r > C[j].r => k[i] = -1.0 / C[i].r,
+                CircleRelation::InternallyTangent => (),
+                _ => return Err(CalcException::NoIntersection),
+            }
+        }
+    }
+    let s = k[0] + k[1] + k[2];
+    let q = 2.0 * (k[0] * k[1] + k[1] * k[2] + k[2] * k[0]).max(0.0).sqrt();
+    let Z = C[0].O * k[0] + C[1].O * k[1] + C[2].O * k[2];
+    let W = cmul(C[0].O, C[1].O) * (k[0] * k[1])
+        + cmul(C[1].O, C[2].O) * (k[1] * k[2])
+        + cmul(C[2].O, C[0].O) * (k[2] * k[0]);
+    let W = csqrt(W) * 2.0;
+    let residual = |d: Circle| {
+        C.iter()
+            .map(|c| {
+                let D = c.O.distance(d.O);
+                (D - (c.r + d.r)).abs().min((D - (c.r - d.r).abs()).abs())
+            })
+            .sum::<f64>()
+    };
+    // The two centers for a curvature, the tangent one first.
+    let solve = |k4: f64| {
+        if k4.abs() < EPSILON {
+            return Err(CalcException::Infinity);
+        }
+        let r = 1.0 / k4.abs();
+        let d1 = Circle { O: (Z + W) / k4, r };
+        let d2 = Circle { O: (Z - W) / k4, r };
+        Ok(if residual(d1) <= residual(d2) {
+            (d1, d2)
+        } else {
+            (d2, d1)
+        })
+    };
+    let (d, e) = solve(s + q)?;
+    // With equal curvatures the two solutions are the two centers.
+    if q < EPSILON {
+        Ok((d, e))
+    } else {
+        Ok((d, solve(s - q)?.0))
+    }
+}
+
+/// Test if a Steiner chain of `n` circles between `inner` and `outer` closes up after one
+/// turn, by the Steiner porism. Inverting to concentric circles with ratio of radii `p`, it
+/// closes exactly when `(p - 1) / (p + 1) = sin(pi / n)`, and `p` is recovered from the
+/// inversive distance.
+/// If `inner` is not strictly inside `outer` return `NoIntersection` error.
+pub fn steiner_chain_closes(inner: Circle, outer: Circle, n: usize) -> Result<bool> {
+    let nested = match circle_relation(inner, outer) {
+        CircleRelation::Contained | CircleRelation::Concentric => inner.r < outer.r,
+        _ => false,
+    };
+    if !nested {
+        return Err(CalcException::NoIntersection);
+    }
+    let d = -inversive_distance(inner, outer);
+    let p = d + (d * d - 1.0).sqrt();
+    let s = (std::f64::consts::PI / n as f64).sin();
+    Ok(((p - 1.0) / (p + 1.0) - s).abs() < EPSILON)
+}
+
+/// Construct the Apollonius circle, the locus of points whose distances to `A` and `B`
+/// are in ratio `k`.
+/// If `k` is `1` the locus is the perpendicular bisector, and return `Infinity` error.
+/// If `k` is nonpositive return `NonpositiveRadius` error.
+pub fn apollonius(A: Point, B: Point, k: f64) -> Result<Circle> {
+    if k <= 0.0 {
+        return Err(CalcException::NonpositiveRadius);
+    }
+    if (k - 1.0).abs() < EPSILON {
+        return Err(CalcException::Infinity);
+    }
+    if A == B {
+        return Err(CalcException::OverlappingPoint);
+    }
+    let P = (A + B * k) / (1.0 + k);
+    let Q = (A - B * k) / (1.0 - k);
+    Circle::from_center_point(midpoint(P, Q), P)
+}
+
+/// Construct the circle tangent to four lines, if there is one.
+/// The center is found among the intersections of the angle bisectors of the lines, and
+/// if several circles are tangent to all four lines, the smallest one is returned.
+pub fn have_common_incircle(l1: Line, l2: Line, l3: Line, l4: Line) -> Option<Circle> {
+    let lines = [l1, l2, l3, l4];
+    let bisectors: Vec<Vec<Line>> = [l2, l3, l4]
+        .iter()
+        .map(|&k| {
+            let (m, n) = angle_bisect(l1, k);
+            [m, n]
+                .into_iter()
+                .filter(|l| l.a.abs() > EPSILON || l.b.abs() > EPSILON)
+                .collect()
+        })
+        .collect();
+    let mut best: Option<Circle> = None;
+    for (i, u) in bisectors.iter().enumerate() {
+        for v in &bisectors[i + 1..] {
+            for &m in u {
+                for &n in v {
+                    let Ok(O) = m.inter(n) else { continue };
+                    let r = O.distance(l1);
+                    if r < EPSILON || lines.iter().any(|&l| (O.distance(l) - r).abs() > EPSILON) {
+                        continue;
+                    }
+                    if best.is_none_or(|c| r < c.r) {
+                        best = Some(Circle { O, r });
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Construct the regular Polygon with `n` vertices on the circle of radius `r` about `O`,
+/// counterclockwise from the vertex at angle `start` to the `x`-axis.
+/// If `n < 3` return `CollinearPoints` error, and if `r` is nonpositive return
+/// `NonpositiveRadius` error.
+pub fn regular_polygon(O: Point, r: f64, n: usize, start: f64) -> Result<Polygon> {
+    if n < 3 {
+        return Err(CalcException::CollinearPoints);
+    }
+    let c = Circle::from_center_radius(O, r)?;
+    let step = std::f64::consts::TAU / n as f64;
+    let V = (0..n).map(|i| c.point_on(start + step * i as f64));
+    Ok(Polygon(V.collect()))
+}
+
+/// Construct the star Polygon `{n/k}`, joining every `k`-th vertex of the regular
+/// `n`-gon inscribed in the circle of radius `r` about `O`, starting at angle `0`.
+/// If it is not a proper star, i.e. unless `gcd(n, k) = 1` and `1 < k < n / 2`, return
+/// `ZeroCoefficient` error. If `r` is nonpositive return `NonpositiveRadius` error.
+pub fn star_polygon(O: Point, r: f64, n: usize, k: usize) -> Result<Polygon> {
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+    if k <= 1 || 2 * k >= n || gcd(n, k) != 1 {
+        return Err(CalcException::ZeroCoefficient);
+    }
+    let V = regular_polygon(O, r, n, 0.0)?.0;
+    Ok(Polygon((0..n).map(|i| V[i * k % n]).collect()))
+}
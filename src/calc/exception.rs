@@ -18,12 +18,22 @@ pub enum CalcException {
     CollinearPoints,
     /// There are no intersection
     NoIntersection,
+    /// Two lines coincide, so they have infinitely many common points
+    CoincidentLines,
     /// Defining something with coefficient all zero, where this is not allowed
     ZeroCoefficient,
     /// Some calculation would directly cause the result to be the point of
     /// infinity / the line of infinity, like the homothetic center of two
     /// equal circles.
     Infinity,
+    /// Indexing a vertex (or other part) of an object that does not have it
+    IndexOutOfRange,
+    /// Applying a transformation that is not a similarity to a Circle, which would
+    /// give an ellipse
+    NotSimilarity,
+    /// A matrix is not the homogeneous matrix of an affine transformation, whose last
+    /// row is `(0, 0, 1)`
+    NotAffine,
 }
 
 pub type Result<T, E = CalcException> = std::result::Result<T, E>;
@@ -37,6 +47,9 @@ impl Display for CalcException {
                 "Three (or more) points are collinear when they shouldn't"
             ),
             CalcException::NoIntersection => write!(f, "There are no intersection"),
+            CalcException::CoincidentLines => {
+                write!(f, "Two lines coincide, so they have infinitely many common points")
+            }
             CalcException::NonpositiveRadius => {
                 write!(f, "Defining a circle with nonpositive radius")
             }
@@ -49,6 +62,17 @@ impl Display for CalcException {
                 f,
                 "This calculation would directly cause the result to be the point of infinity / the line of infinity"
             ),
+            CalcException::IndexOutOfRange => {
+                write!(f, "Indexing a vertex of an object that does not have it")
+            }
+            CalcException::NotSimilarity => write!(
+                f,
+                "Applying a transformation that is not a similarity to a Circle"
+            ),
+            CalcException::NotAffine => write!(
+                f,
+                "A matrix is not the homogeneous matrix of an affine transformation"
+            ),
         }
     }
 }
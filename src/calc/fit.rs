@@ -0,0 +1,79 @@
+#![allow(non_snake_case)]
+
+use crate::objects::{Circle, Line, Point};
+
+use super::{
+    constants::EPSILON,
+    construct::center,
+    exception::{CalcException, Result},
+};
+
+/// Fit a Circle to sample Points by the algebraic Kåsa method, i.e. least squares of
+/// `x^2 + y^2 + Dx + Ey + F` over the samples. Coordinates are centered at the mean
+/// first for numerical stability.
+/// If there are fewer than three Points, or they are all collinear, return
+/// `CollinearPoints` error.
+pub fn fit_circle(points: &[Point]) -> Result<Circle> {
+    if points.len() < 3 {
+        return Err(CalcException::CollinearPoints);
+    }
+    let M = center(&points.to_vec());
+    let (mut suu, mut suv, mut svv) = (0.0, 0.0, 0.0);
+    let (mut su3, mut sv3, mut suvv, mut svuu) = (0.0, 0.0, 0.0, 0.0);
+    for &P in points {
+        let (u, v) = (P.x - M.x, P.y - M.y);
+        suu += u * u;
+        suv += u * v;
+        svv += v * v;
+        su3 += u * u * u;
+        sv3 += v * v * v;
+        suvv += u * v * v;
+        svuu += v * u * u;
+    }
+    let det = suu * svv - suv * suv;
+    if det <= EPSILON * (suu + svv) * (suu + svv) {
+        return Err(CalcException::CollinearPoints);
+    }
+    let (p, q) = ((su3 + suvv) / 2.0, (sv3 + svuu) / 2.0);
+    let uc = (p * svv - q * suv) / det;
+    let vc = (q * suu - p * suv) / det;
+    let r2 = uc * uc + vc * vc + (suu + svv) / points.len() as f64;
+    if r2 <= 0.0 {
+        return Err(CalcException::NonpositiveRadius);
+    }
+    Ok(Circle {
+        O: Point {
+            x: M.x + uc,
+            y: M.y + vc,
+        },
+        r: r2.sqrt(),
+    })
+}
+
+/// Fit a Line to sample Points by total least squares, i.e. along the principal axis of
+/// their covariance matrix, so vertical Lines are handled like any other.
+/// The result is normalized, with `a^2 + b^2 = 1`.
+/// If the Points all coincide (or there are none) return `OverlappingPoint` error.
+pub fn fit_line(points: &[Point]) -> Result<Line> {
+    if points.is_empty() {
+        return Err(CalcException::OverlappingPoint);
+    }
+    let M = center(&points.to_vec());
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+    for &P in points {
+        let (u, v) = (P.x - M.x, P.y - M.y);
+        sxx += u * u;
+        sxy += u * v;
+        syy += v * v;
+    }
+    if sxx + syy < EPSILON {
+        return Err(CalcException::OverlappingPoint);
+    }
+    let t = (2.0 * sxy).atan2(sxx - syy) / 2.0;
+    let (a, b) = (-t.sin(), t.cos());
+    Ok(Line {
+        a,
+        b,
+        c: -(a * M.x + b * M.y),
+    })
+}
@@ -0,0 +1,114 @@
+#![allow(non_snake_case)]
+
+use crate::objects::{Circle, Point};
+
+use super::{
+    basic::{cmp_lex, orientation, Distance},
+    constants::EPSILON,
+    construct::midpoint,
+};
+
+/// The convex hull of a set of Points, by Andrew's monotone chain.
+/// Returns the vertices in counterclockwise order, starting from the lexicographically
+/// smallest. Points on a hull edge are dropped and duplicate Points (within `EPSILON`)
+/// are merged, so collinear input gives its two extreme Points, and a single distinct
+/// Point gives itself.
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(cmp_lex);
+    // A Point within `EPSILON` of `A` has `x` within `EPSILON` of `A.x`, but other Points
+    // may sort between them, so check all the kept Points in that range.
+    let mut P: Vec<Point> = Vec::with_capacity(sorted.len());
+    for A in sorted {
+        if !P
+            .iter()
+            .rev()
+            .take_while(|K| A.x - K.x < EPSILON)
+            .any(|&K| K == A)
+        {
+            P.push(A);
+        }
+    }
+    if P.len() < 3 {
+        return P;
+    }
+    let mut H: Vec<Point> = Vec::with_capacity(P.len() + 1);
+    // The lower hull, then the upper hull back to the first Point.
+    for &A in &P {
+        while H.len() >= 2 && orientation(H[H.len() - 2], H[H.len() - 1], A) <= 0 {
+            H.pop();
+        }
+        H.push(A);
+    }
+    let lower = H.len() + 1;
+    for &A in P.iter().rev().skip(1) {
+        while H.len() >= lower && orientation(H[H.len() - 2], H[H.len() - 1], A) <= 0 {
+            H.pop();
+        }
+        H.push(A);
+    }
+    H.pop();
+    H
+}
+
+/// The Circle with two Points as a diameter.
+#[inline]
+fn diameter_circle(A: Point, B: Point) -> Circle {
+    Circle {
+        O: midpoint(A, B),
+        r: A.distance(B) / 2.0,
+    }
+}
+
+/// The smallest Circle with three Points on or inside it and all three on its boundary
+/// when possible. Collinear Points fall back to the diameter of the farthest pair.
+fn boundary_circle(A: Point, B: Point, C: Point) -> Circle {
+    if orientation(A, B, C) != 0 {
+        if let Ok(c) = Circle::from_3p(A, B, C) {
+            return c;
+        }
+    }
+    [(A, B), (B, C), (C, A)]
+        .into_iter()
+        .map(|(P, Q)| diameter_circle(P, Q))
+        .max_by(|c, d| c.r.total_cmp(&d.r))
+        .unwrap()
+}
+
+/// The smallest Circle containing a set of Points, by Welzl's algorithm.
+/// The Points are shuffled with a fixed seed first for expected linear time, so the
+/// result is deterministic. Returns `None` for empty input, and for a single distinct
+/// Point a Circle of radius `0` centered at it.
+pub fn min_enclosing_circle(points: &[Point]) -> Option<Circle> {
+    let mut P = points.to_vec();
+    let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+    for i in (1..P.len()).rev() {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        P.swap(i, (seed % (i as u64 + 1)) as usize);
+    }
+    let inside = |c: &Circle, A: Point| c.O.distance(A) <= c.r + EPSILON;
+    let mut c = Circle {
+        O: *P.first()?,
+        r: 0.0,
+    };
+    for i in 1..P.len() {
+        if inside(&c, P[i]) {
+            continue;
+        }
+        c = Circle { O: P[i], r: 0.0 };
+        for j in 0..i {
+            if inside(&c, P[j]) {
+                continue;
+            }
+            c = diameter_circle(P[i], P[j]);
+            for k in 0..j {
+                if !inside(&c, P[k]) {
+                    c = boundary_circle(P[i], P[j], P[k]);
+                }
+            }
+        }
+    }
+    Some(c)
+}
@@ -1,4 +1,9 @@
-use crate::objects::{Circle, Point};
+use crate::objects::{Circle, Ellipse, Line, Point};
+
+use super::{
+    constants::{DEG, ORIGIN},
+    construct::projection,
+};
 
 /// Trait for constructing a point on another object by a parameter `pos` controlling position.
 pub trait PointOn {
@@ -16,3 +21,34 @@ impl PointOn for Circle {
         }
     }
 }
+
+impl Circle {
+    /// Construct a point `A` on circle, by the angle `AOx` in degrees.
+    #[inline]
+    pub fn point_on_deg(&self, deg: f64) -> Point {
+        self.point_on(deg * DEG)
+    }
+}
+
+impl PointOn for Ellipse {
+    /// Construct a point on ellipse by its parametric angle `t`, i.e. the point
+    /// `(a cos t, b sin t)` in the frame of the axes.
+    #[inline]
+    fn point_on(&self, t: f64) -> Point {
+        let (s, c) = self.angle.sin_cos();
+        let (u, v) = (self.a * t.cos(), self.b * t.sin());
+        Point {
+            x: self.O.x + u * c - v * s,
+            y: self.O.y + u * s + v * c,
+        }
+    }
+}
+
+impl PointOn for Line {
+    /// Construct a point on line by its signed distance `t` from the foot of the
+    /// perpendicular from the origin, in the direction `(-b, a)` of `ax + by + c = 0`.
+    #[inline]
+    fn point_on(&self, t: f64) -> Point {
+        projection(ORIGIN, *self) + self.direction() * t
+    }
+}
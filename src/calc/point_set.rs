@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+
+use crate::objects::{Line, Point};
+
+use super::basic::{cmp_lex, Distance};
+
+/// Find the two closest points in a point set, returning their indices and distance.
+/// Returns `None` if there are fewer than two points.
+/// This uses the divide-and-conquer algorithm, which runs in `O(n log n)`.
+pub fn closest_pair(points: &[Point]) -> Option<(usize, usize, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mut idx: Vec<usize> = (0..points.len()).collect();
+    idx.sort_by(|&i, &j| cmp_lex(&points[i], &points[j]));
+    let (i, j, d) = closest_pair_sorted(points, &mut idx);
+    Some((i.min(j), i.max(j), d.sqrt()))
+}
+
+/// Sort points along a Line, in ascending order along the direction `(-b, a)` of
+/// `ax + by + c = 0`.
+/// Points not on the Line are ordered by their projections on it.
+pub fn sort_along_line(points: &mut [Point], l: Line) {
+    let d = Point { x: -l.b, y: l.a };
+    points.sort_by(|P, Q| P.dot(d).total_cmp(&Q.dot(d)));
+}
+
+/// The recursive step of `closest_pair`. `idx` must be sorted by `x` on entry, and is
+/// sorted by `y` on exit. Returns the squared distance.
+fn closest_pair_sorted(points: &[Point], idx: &mut [usize]) -> (usize, usize, f64) {
+    let n = idx.len();
+    if n <= 3 {
+        let mut best = (idx[0], idx[1], f64::INFINITY);
+        for i in 0..n {
+            for j in i + 1..n {
+                let d = points[idx[i]].distance_sq(points[idx[j]]);
+                if d < best.2 {
+                    best = (idx[i], idx[j], d);
+                }
+            }
+        }
+        idx.sort_by(|&i, &j| points[i].y.total_cmp(&points[j].y));
+        return best;
+    }
+    let mid = n / 2;
+    let x = points[idx[mid]].x;
+    let (left, right) = idx.split_at_mut(mid);
+    let l = closest_pair_sorted(points, left);
+    let r = closest_pair_sorted(points, right);
+    let mut best = if l.2 <= r.2 { l } else { r };
+    // Merge the two halves by `y`.
+    let mut merged = Vec::with_capacity(n);
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < n {
+        if points[idx[i]].y <= points[idx[j]].y {
+            merged.push(idx[i]);
+            i += 1;
+        } else {
+            merged.push(idx[j]);
+            j += 1;
+        }
+    }
+    merged.extend_from_slice(&idx[i..mid]);
+    merged.extend_from_slice(&idx[j..n]);
+    idx.copy_from_slice(&merged);
+    // Check the strip around the dividing line.
+    let strip: Vec<usize> = idx
+        .iter()
+        .copied()
+        .filter(|&k| (points[k].x - x).powi(2) < best.2)
+        .collect();
+    for (s, &p) in strip.iter().enumerate() {
+        for &q in &strip[s + 1..] {
+            if (points[q].y - points[p].y).powi(2) >= best.2 {
+                break;
+            }
+            let d = points[p].distance_sq(points[q]);
+            if d < best.2 {
+                best = (p, q, d);
+            }
+        }
+    }
+    best
+}
@@ -0,0 +1,109 @@
+#![allow(non_snake_case)]
+
+use std::f64::consts::TAU;
+
+use crate::objects::{Point, Polygon, Segment};
+
+use super::{
+    basic::{orientation, Distance},
+    construct::center,
+};
+
+impl Polygon {
+    /// The edges of the Polygon as pairs of consecutive vertices, closing the last
+    /// vertex back to the first.
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let n = self.0.len();
+        (0..n).map(move |i| (self.0[i], self.0[(i + 1) % n]))
+    }
+    /// The signed area of the Polygon by the shoelace formula, positive if the vertices
+    /// are counterclockwise.
+    pub fn signed_area(&self) -> f64 {
+        self.edges().map(|(P, Q)| P.cross(Q)).sum::<f64>() / 2.0
+    }
+    /// The area of the Polygon. The Polygon should not be self-intersecting.
+    #[inline]
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+    /// The centroid of the region bounded by the Polygon, i.e. its center of mass as a
+    /// lamina. This differs from `construct::center`, which is the average of the
+    /// vertices and depends on how the boundary is subdivided. If the Polygon has zero
+    /// area, fall back to the average of the vertices.
+    pub fn centroid(&self) -> Point {
+        let S = self.signed_area();
+        if S == 0.0 {
+            return center(&self.0);
+        }
+        let s = self.edges().fold(Point { x: 0.0, y: 0.0 }, |s, (P, Q)| {
+            s + (P + Q) * P.cross(Q)
+        });
+        s / (6.0 * S)
+    }
+    /// Test if a Point is inside the Polygon by its winding number, so non-convex
+    /// Polygons are handled and self-intersecting ones use the nonzero rule.
+    /// Points on an edge, within `EPSILON`, count as inside. Repeated vertices are skipped.
+    pub fn contains(&self, P: Point) -> bool {
+        let mut w = 0;
+        for (A, B) in self.edges() {
+            if A == B {
+                continue;
+            }
+            let o = orientation(A, B, P);
+            if o == 0 && (P - A).dot(B - A) >= 0.0 && (P - B).dot(A - B) >= 0.0 {
+                return true;
+            }
+            if A.y <= P.y {
+                if B.y > P.y && o > 0 {
+                    w += 1;
+                }
+            } else if B.y <= P.y && o < 0 {
+                w -= 1;
+            }
+        }
+        w != 0
+    }
+    /// The distance from a Point to the nearest edge of the Polygon.
+    /// An empty Polygon has no boundary, and the distance is infinite.
+    pub fn distance_to_boundary(&self, P: Point) -> f64 {
+        self.edges()
+            .map(|(A, B)| P.distance(Segment { A, B }))
+            .fold(f64::INFINITY, f64::min)
+    }
+    /// The signed distance from a Point to the boundary, negative inside the Polygon and
+    /// positive outside, with the sign decided by `contains`.
+    pub fn signed_distance(&self, P: Point) -> f64 {
+        let d = self.distance_to_boundary(P);
+        if self.contains(P) {
+            -d
+        } else {
+            d
+        }
+    }
+    /// Test if the Polygon is convex. Collinear and repeated vertices are ignored, and a
+    /// Polygon with no turns, or whose boundary winds more than once, is not convex.
+    pub fn is_convex(&self) -> bool {
+        let mut V = self.0.clone();
+        V.dedup();
+        while V.len() > 1 && V.first() == V.last() {
+            V.pop();
+        }
+        let n = V.len();
+        let mut sign = 0;
+        let mut turn = 0.0;
+        for i in 0..n {
+            let (A, B, C) = (V[i], V[(i + 1) % n], V[(i + 2) % n]);
+            let o = orientation(A, B, C);
+            if o == 0 {
+                continue;
+            }
+            if sign != 0 && o != sign {
+                return false;
+            }
+            sign = o;
+            let (u, v) = (B - A, C - B);
+            turn += u.cross(v).atan2(u.dot(v));
+        }
+        sign != 0 && (turn.abs() - TAU).abs() < 1e-6
+    }
+}
@@ -0,0 +1,74 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use super::constants::EPSILON;
+
+/// A floating point type the objects can be built on, `f64` by default or `f32`.
+/// The core of the crate, i.e. the arithmetic of Points, the construction of Lines and
+/// Circles, the intersection of Lines and the approximate equality, is generic over it.
+/// The rest of the calculations are on `f64`.
+pub trait Scalar:
+    Copy
+    + PartialOrd
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// The tolerance of approximate comparison for this type.
+    const EPSILON: Self;
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($t:ty, $eps:expr) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const EPSILON: Self = $eps;
+            #[inline]
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+            #[inline]
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+            #[inline]
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+            #[inline]
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+            #[inline]
+            fn acos(self) -> Self {
+                <$t>::acos(self)
+            }
+            #[inline]
+            fn atan2(self, x: Self) -> Self {
+                <$t>::atan2(self, x)
+            }
+        }
+    };
+}
+
+impl_scalar!(f64, EPSILON);
+impl_scalar!(f32, 1e-5);
@@ -1,14 +1,17 @@
 #![allow(non_snake_case)]
 
-use crate::objects::{Circle, Line, Point};
+use crate::objects::{Circle, Ellipse, Line, Point, Polygon, Segment};
 
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
 use super::{
-    basic::{is_parallel, Distance, Intersect, TestThrough},
+    basic::{Distance, Intersect, TestThrough},
+    complex::{cdiv, cmul},
+    constants::{DEG, EPSILON},
     construct::{midpoint, perp, projection},
     exception::{CalcException, Result},
+    point_on::PointOn,
 };
 
 /// A trait for reflection (in `T`). Provides `reflect_in` function.
@@ -38,6 +41,22 @@ impl Reflect<Line> for Point {
     }
 }
 
+impl Reflect<Segment> for Point {
+    /// Reflect Point in the supporting Line of a Segment.
+    /// See also `reflect_in_segment_clamped`.
+    #[inline]
+    fn reflect_in(self, s: Segment) -> Self {
+        self.reflect_in(s.to_line())
+    }
+}
+
+/// Reflect a Point in the nearest point of a Segment.
+/// Unlike `Reflect<Segment>`, which reflects in the whole supporting Line, this reflects
+/// in the nearest endpoint when the foot of the perpendicular falls outside the Segment.
+pub fn reflect_in_segment_clamped(P: Point, s: Segment) -> Point {
+    P.reflect_in(s.closest_point(P))
+}
+
 impl Reflect<Point> for Line {
     /// Reflect Line in Point.
     #[inline]
@@ -53,18 +72,23 @@ impl Reflect<Point> for Line {
 impl Reflect<Line> for Line {
     /// Reflect Line in Line.
     fn reflect_in(self, l: Line) -> Self {
-        if is_parallel(self, l) {
-            Line {
-                a: self.a,
-                b: self.b,
-                c: 2.0 * l.c - self.c,
+        match self.inter(l) {
+            Ok(P) => {
+                let (a, b) = (l.a, l.b);
+                let (c, d) = (self.a, self.b);
+                let a0 = a * a * c + 2.0 * a * b * d - b * b * c;
+                let b0 = 2.0 * a * b * c + (b * b - a * a) * d;
+                Line::from_slope_and_point(a0, b0, P)
+            }
+            // Parallel or coincident, scale `l` to the coefficients of `self` first.
+            Err(_) => {
+                let k = (self.a * l.a + self.b * l.b) / (l.a * l.a + l.b * l.b);
+                Line {
+                    a: self.a,
+                    b: self.b,
+                    c: 2.0 * k * l.c - self.c,
+                }
             }
-        } else {
-            let (a, b) = (l.a, l.b);
-            let (c, d) = (self.a, self.b);
-            let a0 = a * a * c + 2.0 * a * b * d - b * b * c;
-            let b0 = 2.0 * a * b * c + (b * b - a * a) * d;
-            Line::from_slope_and_point(a0, b0, self.inter(l).unwrap())
         }
     }
 }
@@ -91,6 +115,28 @@ impl Reflect<Line> for Circle {
     }
 }
 
+impl Reflect<Point> for Segment {
+    /// Reflect Segment in Point.
+    #[inline]
+    fn reflect_in(self, P: Point) -> Self {
+        Segment {
+            A: self.A.reflect_in(P),
+            B: self.B.reflect_in(P),
+        }
+    }
+}
+
+impl Reflect<Line> for Segment {
+    /// Reflect Segment in Line.
+    #[inline]
+    fn reflect_in(self, l: Line) -> Self {
+        Segment {
+            A: self.A.reflect_in(l),
+            B: self.B.reflect_in(l),
+        }
+    }
+}
+
 /// A trait for (circular) inversion. Provides `invert_in` function.
 pub trait Invert {
     /// The type of the inverted shape.
@@ -110,8 +156,11 @@ pub enum LineInverted {
 impl Invert for Point {
     type Inverted = Result<Point>;
     /// Invert a Point.
+    /// If the power `p` is zero return `ZeroCoefficient` error.
     fn invert_in(self, O: Point, p: f64) -> Self::Inverted {
-        if self == O {
+        if p == 0.0 {
+            Err(CalcException::ZeroCoefficient)
+        } else if self == O {
             Err(CalcException::OverlappingPoint)
         } else {
             let d = self - O;
@@ -122,32 +171,94 @@ impl Invert for Point {
 }
 
 impl Invert for Line {
-    type Inverted = LineInverted;
+    type Inverted = Result<LineInverted>;
     /// Invert a Line.
+    /// If the power `p` is zero return `ZeroCoefficient` error.
     fn invert_in(self, O: Point, p: f64) -> Self::Inverted {
-        if self.is_through(O) {
-            LineInverted::Line(self)
+        if p == 0.0 {
+            Err(CalcException::ZeroCoefficient)
+        } else if self.is_through(O) {
+            Ok(LineInverted::Line(self))
         } else {
             let t = projection(O, self);
-            let o1 = midpoint(t.invert_in(O, p).unwrap(), O);
-            LineInverted::Circle(Circle::from_center_point(o1, O).unwrap())
+            let o1 = midpoint(t.invert_in(O, p)?, O);
+            Ok(LineInverted::Circle(Circle::from_center_point(o1, O)?))
         }
     }
 }
 
 impl Invert for Circle {
-    type Inverted = LineInverted;
-    /// Invert a Circle.
+    type Inverted = Result<LineInverted>;
+    /// Invert a Circle. A Circle about the center of inversion stays concentric.
+    /// If the power `p` is zero return `ZeroCoefficient` error.
     fn invert_in(self, O: Point, p: f64) -> Self::Inverted {
-        if self.is_through(O) {
-            let m = midpoint(self.O.invert_in(O, p).unwrap(), O);
-            LineInverted::Line(perp(m, Line::from_2p(m, O).unwrap()))
+        if p == 0.0 {
+            Err(CalcException::ZeroCoefficient)
+        } else if self.is_through(O) {
+            let m = midpoint(self.O.invert_in(O, p)?, O);
+            Ok(LineInverted::Line(perp(m, Line::from_2p(m, O)?)))
+        } else if self.O == O {
+            Ok(LineInverted::Circle(Circle {
+                O,
+                r: p.abs() / self.r,
+            }))
         } else {
-            let (a, b) = self.inter(Line::from_2p(O, self.O).unwrap()).unwrap();
-            let a0 = a.invert_in(O, p).unwrap();
-            let b0 = b.invert_in(O, p).unwrap();
+            let (a, b) = self.inter(Line::from_2p(O, self.O)?)?;
+            let a0 = a.invert_in(O, p)?;
+            let b0 = b.invert_in(O, p)?;
             let o1 = midpoint(a0, b0);
-            LineInverted::Circle(Circle::from_center_point(o1, a0).unwrap())
+            Ok(LineInverted::Circle(Circle::from_center_point(o1, a0)?))
+        }
+    }
+}
+
+/// The possible results of an inversion of a Segment.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, PartialEq)]
+pub enum ArcOrSegment {
+    /// The arc of a Circle running counterclockwise from `start` to `end`
+    Arc {
+        c: Circle,
+        start: Point,
+        end: Point,
+    },
+    Segment(Segment),
+}
+
+/// The counterclockwise angle from `OA` to `OB`, in `[0, 2pi)`.
+#[inline]
+fn ccw_angle(O: Point, A: Point, B: Point) -> f64 {
+    let (u, v) = (A - O, B - O);
+    u.cross(v).atan2(u.dot(v)).rem_euclid(std::f64::consts::TAU)
+}
+
+impl Invert for Segment {
+    type Inverted = Result<ArcOrSegment>;
+    /// Invert a Segment. If its supporting Line passes through the center it stays a
+    /// Segment; otherwise it becomes the arc between the inverted endpoints not containing
+    /// the center.
+    /// If the center is an endpoint return `OverlappingPoint` error, and if it is inside the
+    /// Segment the image is unbounded, and return `Infinity` error.
+    /// If the power `p` is zero return `ZeroCoefficient` error.
+    fn invert_in(self, O: Point, p: f64) -> Self::Inverted {
+        let A = self.A.invert_in(O, p)?;
+        let B = self.B.invert_in(O, p)?;
+        match self.to_line().invert_in(O, p)? {
+            LineInverted::Line(_) => {
+                if (self.A - O).dot(self.B - O) < 0.0 {
+                    Err(CalcException::Infinity)
+                } else {
+                    Ok(ArcOrSegment::Segment(Segment { A, B }))
+                }
+            }
+            LineInverted::Circle(c) => {
+                let (start, end) = if ccw_angle(c.O, A, O) < ccw_angle(c.O, A, B) {
+                    (B, A)
+                } else {
+                    (A, B)
+                };
+                Ok(ArcOrSegment::Arc { c, start, end })
+            }
         }
     }
 }
@@ -158,6 +269,14 @@ impl Invert for Circle {
 pub trait Rotate {
     /// Rotate by angle.
     fn rotate(self, O: Point, angle: f64) -> Self;
+    /// Rotate by angle in degrees.
+    #[inline]
+    fn rotate_deg(self, O: Point, deg: f64) -> Self
+    where
+        Self: Sized,
+    {
+        self.rotate(O, deg * DEG)
+    }
 }
 
 impl Rotate for Point {
@@ -182,7 +301,7 @@ impl Rotate for Line {
         let Line { a, b, c } = self;
         let a0 = a * cos - b * sin;
         let b0 = b * cos + a * sin;
-        let c0 = a * O.x + b * O.y + c;
+        let c0 = a * O.x + b * O.y + c - a0 * O.x - b0 * O.y;
         Line {
             a: a0,
             b: b0,
@@ -202,6 +321,17 @@ impl Rotate for Circle {
     }
 }
 
+impl Rotate for Segment {
+    /// Rotate a Segment around a Point by angle.
+    #[inline]
+    fn rotate(self, O: Point, angle: f64) -> Self {
+        Segment {
+            A: self.A.rotate(O, angle),
+            B: self.B.rotate(O, angle),
+        }
+    }
+}
+
 /// A trait for scaling.
 pub trait Scale {
     /// Scale an object with center `O` and ratio `r`.
@@ -235,3 +365,466 @@ impl Scale for Circle {
         }
     }
 }
+
+impl Scale for Segment {
+    #[inline]
+    fn scale(self, O: Point, r: f64) -> Self {
+        Segment {
+            A: self.A.scale(O, r),
+            B: self.B.scale(O, r),
+        }
+    }
+}
+
+/// Scale a Circle with center `O` by the ratios `rx` along the `x`-axis and `ry` along the
+/// `y`-axis, which gives an axis-aligned Ellipse.
+pub fn scale_xy(c: Circle, O: Point, rx: f64, ry: f64) -> Ellipse {
+    Ellipse {
+        O: Point {
+            x: O.x + (c.O.x - O.x) * rx,
+            y: O.y + (c.O.y - O.y) * ry,
+        },
+        a: c.r * rx.abs(),
+        b: c.r * ry.abs(),
+        angle: 0.0,
+    }
+}
+
+/// A trait for translation.
+pub trait Translate {
+    /// Translate an object by a vector.
+    fn translate(self, by: Point) -> Self;
+}
+
+impl Translate for Point {
+    #[inline]
+    fn translate(self, by: Point) -> Self {
+        self + by
+    }
+}
+
+impl Translate for Line {
+    #[inline]
+    fn translate(self, by: Point) -> Self {
+        Line {
+            a: self.a,
+            b: self.b,
+            c: self.c - (self.a * by.x + self.b * by.y),
+        }
+    }
+}
+
+impl Translate for Circle {
+    #[inline]
+    fn translate(self, by: Point) -> Self {
+        Circle {
+            O: self.O + by,
+            r: self.r,
+        }
+    }
+}
+
+impl Translate for Segment {
+    #[inline]
+    fn translate(self, by: Point) -> Self {
+        Segment {
+            A: self.A + by,
+            B: self.B + by,
+        }
+    }
+}
+
+/// Glide reflection, the reflection in `axis` followed by the translation by `distance`
+/// along it, in the direction `(-b, a)` of `ax + by + c = 0`.
+#[inline]
+pub fn glide_reflect<T: Reflect<Line> + Translate>(obj: T, axis: Line, distance: f64) -> T {
+    obj.reflect_in(axis).translate(axis.direction() * distance)
+}
+
+// Collections are transformed elementwise. The traits return `Self`, which a slice
+// cannot do for a `Vec`, so slices are transformed by `to_vec` first.
+
+impl<T: Reflect<U>, U: Copy> Reflect<U> for Vec<T> {
+    #[inline]
+    fn reflect_in(self, obj: U) -> Self {
+        self.into_iter().map(|x| x.reflect_in(obj)).collect()
+    }
+}
+
+impl<T: Rotate> Rotate for Vec<T> {
+    #[inline]
+    fn rotate(self, O: Point, angle: f64) -> Self {
+        self.into_iter().map(|x| x.rotate(O, angle)).collect()
+    }
+}
+
+impl<T: Scale> Scale for Vec<T> {
+    #[inline]
+    fn scale(self, O: Point, r: f64) -> Self {
+        self.into_iter().map(|x| x.scale(O, r)).collect()
+    }
+}
+
+impl<T: Translate> Translate for Vec<T> {
+    #[inline]
+    fn translate(self, by: Point) -> Self {
+        self.into_iter().map(|x| x.translate(by)).collect()
+    }
+}
+
+impl<U: Copy> Reflect<U> for Polygon
+where
+    Point: Reflect<U>,
+{
+    #[inline]
+    fn reflect_in(self, obj: U) -> Self {
+        Polygon(self.0.reflect_in(obj))
+    }
+}
+
+impl Rotate for Polygon {
+    #[inline]
+    fn rotate(self, O: Point, angle: f64) -> Self {
+        Polygon(self.0.rotate(O, angle))
+    }
+}
+
+impl Scale for Polygon {
+    #[inline]
+    fn scale(self, O: Point, r: f64) -> Self {
+        Polygon(self.0.scale(O, r))
+    }
+}
+
+impl Translate for Polygon {
+    #[inline]
+    fn translate(self, by: Point) -> Self {
+        Polygon(self.0.translate(by))
+    }
+}
+
+/// An affine transformation `P -> MP + t`, by its linear part `m` (row-major) and its
+/// translation `t`.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Affine {
+    pub m: [[f64; 2]; 2],
+    pub t: Point,
+}
+
+impl Affine {
+    /// The identity transformation.
+    #[inline]
+    pub fn identity() -> Self {
+        Affine {
+            m: [[1.0, 0.0], [0.0, 1.0]],
+            t: Point { x: 0.0, y: 0.0 },
+        }
+    }
+    /// The translation by a vector.
+    #[inline]
+    pub fn translation(by: Point) -> Self {
+        Affine {
+            t: by,
+            ..Affine::identity()
+        }
+    }
+    /// The counterclockwise rotation around `O` by angle, same as `Rotate`.
+    pub fn rotation(O: Point, angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Affine {
+            m: [[c, -s], [s, c]],
+            t: Point {
+                x: O.x - c * O.x + s * O.y,
+                y: O.y - s * O.x - c * O.y,
+            },
+        }
+    }
+    /// The scaling with center `O` and ratio `r`, same as `Scale`.
+    #[inline]
+    pub fn scaling(O: Point, r: f64) -> Self {
+        Affine {
+            m: [[r, 0.0], [0.0, r]],
+            t: O * (1.0 - r),
+        }
+    }
+    /// The reflection in a Line, same as `Reflect<Line>`.
+    pub fn reflection(l: Line) -> Self {
+        let Line { a, b, c } = l;
+        let n = a * a + b * b;
+        Affine {
+            m: [
+                [1.0 - 2.0 * a * a / n, -2.0 * a * b / n],
+                [-2.0 * a * b / n, 1.0 - 2.0 * b * b / n],
+            ],
+            t: Point {
+                x: -2.0 * a * c / n,
+                y: -2.0 * b * c / n,
+            },
+        }
+    }
+    /// Apply the transformation to a Point.
+    #[inline]
+    pub fn apply(self, P: Point) -> Point {
+        let m = self.m;
+        Point {
+            x: m[0][0] * P.x + m[0][1] * P.y + self.t.x,
+            y: m[1][0] * P.x + m[1][1] * P.y + self.t.y,
+        }
+    }
+    /// Apply the transformation to a Line, by transforming its coefficients with the
+    /// adjugate of the linear part. The transformation should be invertible.
+    pub fn apply_line(self, l: Line) -> Line {
+        let m = self.m;
+        let d = self.det().signum();
+        let a = (l.a * m[1][1] - l.b * m[1][0]) * d;
+        let b = (l.b * m[0][0] - l.a * m[0][1]) * d;
+        Line {
+            a,
+            b,
+            c: l.c * self.det().abs() - a * self.t.x - b * self.t.y,
+        }
+    }
+    /// Apply the transformation to a Circle.
+    /// A Circle stays a Circle only under similarities, so if the linear part is not a
+    /// rotation or reflection times a scaling return `NotSimilarity` error.
+    pub fn apply_circle(self, c: Circle) -> Result<Circle> {
+        let m = self.m;
+        let rotation = (m[0][0] - m[1][1]).abs() < EPSILON && (m[0][1] + m[1][0]).abs() < EPSILON;
+        let reflection = (m[0][0] + m[1][1]).abs() < EPSILON && (m[0][1] - m[1][0]).abs() < EPSILON;
+        if !(rotation || reflection) {
+            return Err(CalcException::NotSimilarity);
+        }
+        Circle::from_center_radius(self.apply(c.O), c.r * self.det().abs().sqrt())
+    }
+    /// The composition applying `self` first, then `other`.
+    pub fn then(self, other: Affine) -> Affine {
+        let (a, b) = (other.m, self.m);
+        let m = [
+            [
+                a[0][0] * b[0][0] + a[0][1] * b[1][0],
+                a[0][0] * b[0][1] + a[0][1] * b[1][1],
+            ],
+            [
+                a[1][0] * b[0][0] + a[1][1] * b[1][0],
+                a[1][0] * b[0][1] + a[1][1] * b[1][1],
+            ],
+        ];
+        Affine {
+            m,
+            t: other.apply(self.t),
+        }
+    }
+    /// The determinant of the linear part.
+    #[inline]
+    pub fn det(self) -> f64 {
+        self.m[0][0] * self.m[1][1] - self.m[0][1] * self.m[1][0]
+    }
+    /// The inverse transformation.
+    /// If the transformation is singular (zero determinant) return `ZeroCoefficient` error.
+    pub fn inverse(self) -> Result<Affine> {
+        let d = self.det();
+        if d.abs() < EPSILON {
+            return Err(CalcException::ZeroCoefficient);
+        }
+        let m = self.m;
+        let m = [[m[1][1] / d, -m[0][1] / d], [-m[1][0] / d, m[0][0] / d]];
+        let t = self.t;
+        Ok(Affine {
+            m,
+            t: Point {
+                x: -(m[0][0] * t.x + m[0][1] * t.y),
+                y: -(m[1][0] * t.x + m[1][1] * t.y),
+            },
+        })
+    }
+}
+
+/// The ratio `(D - C) / (B - A)` as complex numbers.
+/// If `A`, `B` or `C`, `D` overlap return `OverlappingPoint` error.
+fn spiral_ratio(A: Point, B: Point, C: Point, D: Point) -> Result<Point> {
+    if A == B || C == D {
+        return Err(CalcException::OverlappingPoint);
+    }
+    Ok(cdiv(D - C, B - A))
+}
+
+/// The spiral similarity, i.e. the rotation followed by the scaling about a common
+/// center, taking `A` to `C` and `B` to `D`.
+/// If `A`, `B` or `C`, `D` overlap return `OverlappingPoint` error. If `AB` and `CD` are
+/// parallel, equal and in the same direction it is a translation, with the center at
+/// infinity, and return `Infinity` error.
+pub fn spiral_similarity(A: Point, B: Point, C: Point, D: Point) -> Result<Affine> {
+    let k = spiral_ratio(A, B, C, D)?;
+    if k == (Point { x: 1.0, y: 0.0 }) {
+        return Err(CalcException::Infinity);
+    }
+    Ok(Affine {
+        m: [[k.x, -k.y], [k.y, k.x]],
+        t: Point {
+            x: C.x - k.x * A.x + k.y * A.y,
+            y: C.y - k.y * A.x - k.x * A.y,
+        },
+    })
+}
+
+/// The center of the spiral similarity taking `A` to `C` and `B` to `D`.
+/// See `spiral_similarity` for the errors.
+pub fn spiral_center(A: Point, B: Point, C: Point, D: Point) -> Result<Point> {
+    let f = spiral_similarity(A, B, C, D)?;
+    // The fixed point of `z -> kz + t` is `t / (1 - k)`.
+    let k = Point {
+        x: f.m[0][0],
+        y: f.m[1][0],
+    };
+    Ok(cdiv(f.t, Point { x: 1.0, y: 0.0 } - k))
+}
+
+/// A Möbius transformation `z -> (az + b) / (cz + d)`, with the complex coefficients
+/// stored as Points. The point at infinity is represented by `None`.
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Mobius {
+    pub a: Point,
+    pub b: Point,
+    pub c: Point,
+    pub d: Point,
+}
+
+/// The complex numbers `0` and `1`.
+const ZERO: Point = Point { x: 0.0, y: 0.0 };
+const ONE: Point = Point { x: 1.0, y: 0.0 };
+
+impl Mobius {
+    /// The identity transformation.
+    #[inline]
+    pub fn identity() -> Self {
+        Mobius {
+            a: ONE,
+            b: ZERO,
+            c: ZERO,
+            d: ONE,
+        }
+    }
+    /// The transformation sending `z[0]`, `z[1]`, `z[2]` to `0`, `1`, infinity.
+    /// If two of the points overlap return `OverlappingPoint` error.
+    fn to_standard(z: [Option<Point>; 3]) -> Result<Mobius> {
+        let distinct = |P: Option<Point>, Q: Option<Point>| match (P, Q) {
+            (None, None) => false,
+            (Some(P), Some(Q)) => P != Q,
+            _ => true,
+        };
+        if !distinct(z[0], z[1]) || !distinct(z[1], z[2]) || !distinct(z[2], z[0]) {
+            return Err(CalcException::OverlappingPoint);
+        }
+        Ok(match z {
+            [None, Some(z2), Some(z3)] => Mobius {
+                a: ZERO,
+                b: z2 - z3,
+                c: ONE,
+                d: ZERO - z3,
+            },
+            [Some(z1), None, Some(z3)] => Mobius {
+                a: ONE,
+                b: ZERO - z1,
+                c: ONE,
+                d: ZERO - z3,
+            },
+            [Some(z1), Some(z2), None] => Mobius {
+                a: ONE,
+                b: ZERO - z1,
+                c: ZERO,
+                d: z2 - z1,
+            },
+            [Some(z1), Some(z2), Some(z3)] => Mobius {
+                a: z2 - z3,
+                b: cmul(ZERO - z1, z2 - z3),
+                c: z2 - z1,
+                d: cmul(ZERO - z3, z2 - z1),
+            },
+            _ => unreachable!(),
+        })
+    }
+    /// The unique transformation sending `z[i]` to `w[i]`, where `None` is the point at
+    /// infinity.
+    /// If two of the `z`, or two of the `w`, overlap return `OverlappingPoint` error.
+    pub fn from_three_pairs(z: [Option<Point>; 3], w: [Option<Point>; 3]) -> Result<Mobius> {
+        Ok(Mobius::to_standard(z)?.compose(Mobius::to_standard(w)?.inverse()?))
+    }
+    /// Apply the transformation to a Point. Returns `None` if `P` is the pole, which is
+    /// sent to infinity.
+    pub fn apply(self, P: Point) -> Option<Point> {
+        let q = cmul(self.c, P) + self.d;
+        if q.norm() < EPSILON {
+            None
+        } else {
+            Some(cdiv(cmul(self.a, P) + self.b, q))
+        }
+    }
+    /// The composition applying `self` first, then `other`.
+    pub fn compose(self, other: Mobius) -> Mobius {
+        let Mobius { a, b, c, d } = self;
+        let Mobius {
+            a: p,
+            b: q,
+            c: r,
+            d: s,
+        } = other;
+        Mobius {
+            a: cmul(p, a) + cmul(q, c),
+            b: cmul(p, b) + cmul(q, d),
+            c: cmul(r, a) + cmul(s, c),
+            d: cmul(r, b) + cmul(s, d),
+        }
+    }
+    /// The determinant `ad - bc`.
+    #[inline]
+    pub fn det(self) -> Point {
+        cmul(self.a, self.d) - cmul(self.b, self.c)
+    }
+    /// The inverse transformation.
+    /// If the determinant is zero the map is constant, and return `ZeroCoefficient` error.
+    pub fn inverse(self) -> Result<Mobius> {
+        if self.det().norm() < EPSILON {
+            return Err(CalcException::ZeroCoefficient);
+        }
+        Ok(Mobius {
+            a: self.d,
+            b: ZERO - self.b,
+            c: ZERO - self.c,
+            d: self.a,
+        })
+    }
+    /// Apply the transformation to a Line or a Circle. The image is a Line exactly when the
+    /// object passes through the pole (for a Line, when the pole is at infinity), and is
+    /// constructed through the images of points sampled on the object.
+    pub fn apply_generalized_circle(self, obj: LineInverted) -> Result<LineInverted> {
+        let pole = if self.c.norm() < EPSILON {
+            None
+        } else {
+            Some(cdiv(ZERO - self.d, self.c))
+        };
+        let (to_line, samples) = match obj {
+            LineInverted::Line(l) => (
+                pole.is_none_or(|P| l.is_through(P)),
+                [-1.0, 0.0, 1.0, 2.0].map(|t| l.point_on(t)),
+            ),
+            LineInverted::Circle(c) => (
+                pole.is_some_and(|P| c.is_through(P)),
+                [0.0, 1.5, 3.0, 4.5].map(|t| c.point_on(t)),
+            ),
+        };
+        let images: Vec<Point> = samples
+            .into_iter()
+            .filter(|&P| pole.is_none_or(|Q| P != Q))
+            .filter_map(|P| self.apply(P))
+            .collect();
+        if to_line {
+            Ok(LineInverted::Line(Line::from_2p(images[0], images[1])?))
+        } else {
+            Ok(LineInverted::Circle(Circle::from_3p(
+                images[0], images[1], images[2],
+            )?))
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::objects::Point;
 
+pub mod basic;
 pub mod centers;
+pub mod construct;
 
 pub type Triangle = (Point, Point, Point);
@@ -0,0 +1,72 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    calc::{
+        basic::{orientation, Distance},
+        exception::{CalcException, Result},
+    },
+    objects::Point,
+};
+
+use super::Triangle;
+
+/// Test if a Point is strictly inside a triangle, regardless of its winding.
+pub fn contains_point((A, B, C): Triangle, P: Point) -> bool {
+    let s = orientation(A, B, P);
+    s != 0 && s == orientation(B, C, P) && s == orientation(C, A, P)
+}
+
+/// Test if a Point is on the boundary of a triangle.
+pub fn on_boundary((A, B, C): Triangle, P: Point) -> bool {
+    let s = [
+        orientation(A, B, P),
+        orientation(B, C, P),
+        orientation(C, A, P),
+    ];
+    s.contains(&0) && !(s.contains(&1) && s.contains(&-1))
+}
+
+/// The signed area of a triangle, positive if `A, B, C` are counterclockwise.
+#[inline]
+pub fn signed_area((A, B, C): Triangle) -> f64 {
+    (B - A).cross(C - A) / 2.0
+}
+
+/// The area of a triangle.
+#[inline]
+pub fn area(t: Triangle) -> f64 {
+    signed_area(t).abs()
+}
+
+/// The side lengths `(a, b, c)` of a triangle, opposite to `A`, `B`, `C` respectively.
+#[inline]
+pub fn side_lengths((A, B, C): Triangle) -> (f64, f64, f64) {
+    (B.distance(C), C.distance(A), A.distance(B))
+}
+
+/// The perimeter of a triangle.
+#[inline]
+pub fn perimeter(t: Triangle) -> f64 {
+    let (a, b, c) = side_lengths(t);
+    a + b + c
+}
+
+/// The interior angle at `O` between `OA` and `OB`, in `[0, pi]`.
+#[inline]
+fn interior_angle(A: Point, O: Point, B: Point) -> f64 {
+    let (u, v) = (A - O, B - O);
+    u.cross(v).abs().atan2(u.dot(v))
+}
+
+/// The three interior angles at `A`, `B`, `C` of a triangle, each in `[0, pi]`.
+/// If any two vertices overlap return `OverlappingPoint` error.
+pub fn angles((A, B, C): Triangle) -> Result<(f64, f64, f64)> {
+    if A == B || B == C || C == A {
+        return Err(CalcException::OverlappingPoint);
+    }
+    Ok((
+        interior_angle(B, A, C),
+        interior_angle(C, B, A),
+        interior_angle(A, C, B),
+    ))
+}
@@ -2,15 +2,19 @@
 
 use crate::{
     calc::{
-        basic::{Distance, Intersect},
-        construct::{angle_bisect_3p, midpoint, perp, perp_bisect},
+        basic::{orientation, Distance, Intersect},
+        constants::EPSILON,
+        construct::{angle_bisect_3p, midpoint, parallel, perp, perp_bisect, projection},
         exception::{CalcException, Result},
-        transform::Reflect,
+        transform::{Reflect, Rotate},
     },
-    objects::{Line, Point},
+    objects::{Circle, Conic, Line, Point},
 };
 
-use super::Triangle;
+use super::{
+    basic::{angles, area, side_lengths, signed_area},
+    Triangle,
+};
 
 /// The isogonal conjugate of a point.
 #[inline]
@@ -22,6 +26,48 @@ pub fn isogonal_conjugate((A, B, C): Triangle, P: Point) -> Result<Point> {
     Line::from_2p(B, P1)?.inter(Line::from_2p(C, P2)?)
 }
 
+/// The isotomic conjugate of a point, the point with reciprocal barycentric coordinates.
+/// If `P` is on a side line the conjugate is at infinity, and return `Infinity` error.
+pub fn isotomic_conjugate(t: Triangle, P: Point) -> Result<Point> {
+    let (u, v, w) = to_barycentric(t, P)?;
+    if u.abs() < EPSILON || v.abs() < EPSILON || w.abs() < EPSILON {
+        return Err(CalcException::Infinity);
+    }
+    from_barycentric(t, (1.0 / u, 1.0 / v, 1.0 / w))
+}
+
+/// The isogonal conjugate of a line, which is a conic through the three vertices.
+/// If the line passes through a vertex the conic degenerates into a pair of lines.
+pub fn isogonal_conjugate_line(t: Triangle, l: Line) -> Result<Conic> {
+    let (A, B, C) = t;
+    let m = barycentric_matrix(t)?;
+    let (a, b, c) = side_lengths(t);
+    // The line `pu + qv + rw = 0` in barycentrics is conjugated to `pa^2vw + qb^2wu + rc^2uv = 0`.
+    let [p, q, r] = [A, B, C].map(|P| l.a * P.x + l.b * P.y + l.c);
+    let s = [
+        [0.0, r * c * c, q * b * b],
+        [r * c * c, 0.0, p * a * a],
+        [q * b * b, p * a * a, 0.0],
+    ];
+    let z = |i: usize, j: usize| -> f64 {
+        let mut z = 0.0;
+        for (u, row) in s.iter().enumerate() {
+            for (v, w) in row.iter().enumerate() {
+                z += m[u][i] * w * m[v][j];
+            }
+        }
+        z
+    };
+    Ok(Conic {
+        a: z(0, 0),
+        b: 2.0 * z(0, 1),
+        c: z(1, 1),
+        d: 2.0 * z(0, 2),
+        e: 2.0 * z(1, 2),
+        f: z(2, 2),
+    })
+}
+
 /// Returns a point from its barycentric coordinates.
 #[inline]
 pub fn from_barycentric((A, B, C): Triangle, (x, y, z): (f64, f64, f64)) -> Result<Point> {
@@ -33,11 +79,102 @@ pub fn from_barycentric((A, B, C): Triangle, (x, y, z): (f64, f64, f64)) -> Resu
     }
 }
 
+/// Returns the normalized barycentric coordinates of a point, computed from the signed
+/// areas of the sub-triangles. If the triangle is degenerate return `CollinearPoints` error.
+pub fn to_barycentric((A, B, C): Triangle, P: Point) -> Result<(f64, f64, f64)> {
+    let S = signed_area((A, B, C));
+    if S.abs() < EPSILON {
+        return Err(CalcException::CollinearPoints);
+    }
+    Ok((
+        signed_area((P, B, C)) / S,
+        signed_area((A, P, C)) / S,
+        signed_area((A, B, P)) / S,
+    ))
+}
+
+/// Construct a point from trilinear coordinates, by scaling each coordinate by the
+/// opposite side and delegating to `from_barycentric`.
+/// If all coordinates are zero return `ZeroCoefficient` error.
+pub fn from_trilinear(t: Triangle, (x, y, z): (f64, f64, f64)) -> Result<Point> {
+    if x == 0.0 && y == 0.0 && z == 0.0 {
+        return Err(CalcException::ZeroCoefficient);
+    }
+    let (a, b, c) = side_lengths(t);
+    from_barycentric(t, (a * x, b * y, c * z))
+}
+
+/// Construct a triangle center from its center function, as in the Encyclopedia of Triangle
+/// Centers: `f(a, b, c)` is the first trilinear coordinate, and the other two are
+/// `f(b, c, a)` and `f(c, a, b)`.
+/// If all coordinates are zero return `ZeroCoefficient` error.
+pub fn triangle_center(t: Triangle, f: impl Fn(f64, f64, f64) -> f64) -> Result<Point> {
+    let (a, b, c) = side_lengths(t);
+    from_trilinear(t, (f(a, b, c), f(b, c, a), f(c, a, b)))
+}
+
+/// Returns the exact trilinear coordinates of a point, i.e. its signed distances to the
+/// sides `BC`, `CA`, `AB`, positive on the side of the triangle.
+/// If the triangle is degenerate return `CollinearPoints` error.
+pub fn to_trilinear(t: Triangle, P: Point) -> Result<(f64, f64, f64)> {
+    let (u, v, w) = to_barycentric(t, P)?;
+    let (a, b, c) = side_lengths(t);
+    let S = 2.0 * area(t);
+    Ok((u * S / a, v * S / b, w * S / c))
+}
+
+/// Returns the matrix converting Cartesian coordinates `(x, y, 1)` to normalized
+/// barycentric coordinates. If the triangle is degenerate return `CollinearPoints` error.
+pub fn barycentric_matrix((A, B, C): Triangle) -> Result<[[f64; 3]; 3]> {
+    let det = A.x * (B.y - C.y) + B.x * (C.y - A.y) + C.x * (A.y - B.y);
+    if det.abs() < EPSILON {
+        return Err(CalcException::CollinearPoints);
+    }
+    Ok([
+        [
+            (B.y - C.y) / det,
+            (C.x - B.x) / det,
+            (B.x * C.y - C.x * B.y) / det,
+        ],
+        [
+            (C.y - A.y) / det,
+            (A.x - C.x) / det,
+            (C.x * A.y - A.x * C.y) / det,
+        ],
+        [
+            (A.y - B.y) / det,
+            (B.x - A.x) / det,
+            (A.x * B.y - B.x * A.y) / det,
+        ],
+    ])
+}
+
+/// Returns the inverse of `barycentric_matrix`, converting normalized barycentric
+/// coordinates to Cartesian coordinates `(x, y, 1)`.
+#[inline]
+pub fn barycentric_matrix_inv((A, B, C): Triangle) -> [[f64; 3]; 3] {
+    [[A.x, B.x, C.x], [A.y, B.y, C.y], [1.0, 1.0, 1.0]]
+}
+
 #[inline]
 pub fn circum((A, B, C): Triangle) -> Result<Point> {
     perp_bisect(A, B)?.inter(perp_bisect(A, C)?)
 }
 
+/// Returns the circumcircle of a triangle.
+/// If the triangle is degenerate return `NoIntersection` error.
+#[inline]
+pub fn circumcircle(t: Triangle) -> Result<Circle> {
+    let O = circum(t)?;
+    Ok(Circle {
+        O,
+        r: O.distance(t.0),
+    })
+}
+
+/// Returns the incenter, the common point of the interior bisectors `.0` from
+/// `angle_bisect_3p`. These are interior for any ordering of the vertices, so this is
+/// never an excenter.
 #[inline]
 pub fn incenter((A, B, C): Triangle) -> Result<Point> {
     angle_bisect_3p(A, C, B)?
@@ -45,7 +182,34 @@ pub fn incenter((A, B, C): Triangle) -> Result<Point> {
         .inter(angle_bisect_3p(A, B, C)?.0)
 }
 
+/// Returns the incircle of a triangle.
+/// If the triangle is degenerate return `CollinearPoints` error.
+pub fn incircle(t: Triangle) -> Result<Circle> {
+    let S = area(t);
+    if S < EPSILON {
+        return Err(CalcException::CollinearPoints);
+    }
+    let (a, b, c) = side_lengths(t);
+    Ok(Circle {
+        O: incenter(t)?,
+        r: 2.0 * S / (a + b + c),
+    })
+}
+
+/// Returns the contact triangle, whose vertices are the points where the incircle touches
+/// `BC`, `CA`, `AB`. The cevians through them meet at the Gergonne point.
+/// If the triangle is degenerate return `CollinearPoints` error.
+pub fn contact_triangle((A, B, C): Triangle) -> Result<Triangle> {
+    let I = incircle((A, B, C))?.O;
+    Ok((
+        projection(I, Line::from_2p(B, C)?),
+        projection(I, Line::from_2p(C, A)?),
+        projection(I, Line::from_2p(A, B)?),
+    ))
+}
+
 /// Returns the excenter **contained in the angle `BAC`**.
+/// The result does not depend on the orientation of the triangle.
 #[inline]
 pub fn excenter((A, B, C): Triangle) -> Result<Point> {
     angle_bisect_3p(B, A, C)?
@@ -53,6 +217,57 @@ pub fn excenter((A, B, C): Triangle) -> Result<Point> {
         .inter(angle_bisect_3p(A, B, C)?.1)
 }
 
+/// Returns the excircle **contained in the angle `BAC`**.
+/// If the triangle is degenerate return `CollinearPoints` error.
+pub fn excircle(t: Triangle) -> Result<Circle> {
+    let S = area(t);
+    if S < EPSILON {
+        return Err(CalcException::CollinearPoints);
+    }
+    let (a, b, c) = side_lengths(t);
+    Ok(Circle {
+        O: excenter(t)?,
+        r: 2.0 * S / (b + c - a),
+    })
+}
+
+/// Returns the three excircles, opposite to `A`, `B`, `C` respectively.
+#[inline]
+pub fn excircles((A, B, C): Triangle) -> Result<(Circle, Circle, Circle)> {
+    Ok((
+        excircle((A, B, C))?,
+        excircle((B, C, A))?,
+        excircle((C, A, B))?,
+    ))
+}
+
+/// Returns the A-mixtilinear incircle, tangent to `AB`, `AC` and internally tangent to the
+/// circumcircle. Its center is on the bisector `AI` with `AO_a = AI / cos^2(A/2)`, and its
+/// radius is `r / cos^2(A/2)`.
+/// If the triangle is degenerate return `CollinearPoints` error.
+pub fn mixtilinear_incircle(t: Triangle) -> Result<Circle> {
+    let A = t.0;
+    let i = incircle(t)?;
+    let (a, _, _) = angles(t)?;
+    let k = (a / 2.0).cos().powi(2);
+    Ok(Circle {
+        O: A + (i.O - A) / k,
+        r: i.r / k,
+    })
+}
+
+/// Returns the point where the A-mixtilinear incircle touches the circumcircle.
+/// This is the second intersection of the circumcircle with the line through the incenter
+/// and the midpoint of the arc `BAC`.
+pub fn mixtilinear_touch_point(t: Triangle) -> Result<Point> {
+    let A = t.0;
+    let c = circumcircle(t)?;
+    let I = incenter(t)?;
+    let (M, _) = Line::from_2p(A, I)?.inter_common(c, A)?;
+    let N = c.O * 2.0 - M;
+    Ok(Line::from_2p(N, I)?.inter_common(c, N)?.0)
+}
+
 #[inline]
 pub fn ortho((A, B, C): Triangle) -> Result<Point> {
     perp(A, Line::from_2p(B, C)?).inter(perp(B, Line::from_2p(A, C)?))
@@ -63,24 +278,153 @@ pub fn centroid((A, B, C): Triangle) -> Point {
     (A + B + C) / 3.0
 }
 
+/// Returns the Euler line of a triangle, through the circumcenter and the orthocenter.
+/// If the triangle is equilateral the centers coincide and return `OverlappingPoint` error.
+#[inline]
+pub fn euler_line(t: Triangle) -> Result<Line> {
+    Line::from_2p(circum(t)?, ortho(t)?)
+}
+
 #[inline]
 pub fn nine_point((A, B, C): Triangle) -> Result<Point> {
     circum((midpoint(A, B), midpoint(C, B), midpoint(A, C)))
 }
 
+/// Returns the nine-point circle of a triangle, whose radius is half the circumradius.
 #[inline]
-pub fn symmedian((A, B, C): Triangle) -> Result<Point> {
-    let a2 = C.distance_sq(B);
-    let b2 = B.distance_sq(A);
-    let c2 = A.distance_sq(C);
-    from_barycentric((A, B, C), (a2, b2, c2))
+pub fn nine_point_circle(t: Triangle) -> Result<Circle> {
+    Ok(Circle {
+        O: nine_point(t)?,
+        r: circumcircle(t)?.r / 2.0,
+    })
+}
+
+/// Returns the nine points on the nine-point circle: the midpoints of `BC`, `CA`, `AB`,
+/// the feet of the altitudes from `A`, `B`, `C`, and the midpoints of `A`, `B`, `C`
+/// to the orthocenter (the Euler points), in that order.
+pub fn nine_points((A, B, C): Triangle) -> Result<[Point; 9]> {
+    let H = ortho((A, B, C))?;
+    Ok([
+        midpoint(B, C),
+        midpoint(C, A),
+        midpoint(A, B),
+        projection(A, Line::from_2p(B, C)?),
+        projection(B, Line::from_2p(C, A)?),
+        projection(C, Line::from_2p(A, B)?),
+        midpoint(A, H),
+        midpoint(B, H),
+        midpoint(C, H),
+    ])
+}
+
+/// Returns the Feuerbach point, where the nine-point circle is internally tangent to the
+/// incircle. It is on the line through the nine-point center and the incenter, beyond the
+/// incenter at distance `r`.
+/// If the triangle is degenerate return `CollinearPoints` error. If the triangle is
+/// equilateral the two circles are concentric and `OverlappingPoint` is returned.
+pub fn feuerbach_point(t: Triangle) -> Result<Point> {
+    let i = incircle(t)?;
+    let N = nine_point_circle(t)?.O;
+    let d = i.O.distance(N);
+    if d < EPSILON {
+        return Err(CalcException::OverlappingPoint);
+    }
+    Ok(i.O + (i.O - N) / d * i.r)
+}
+
+/// Returns the common tangent of the incircle and the nine-point circle at their point
+/// of tangency, the Feuerbach point.
+/// If the triangle is degenerate return `CollinearPoints` error. If the triangle is
+/// equilateral the two circles are concentric and `OverlappingPoint` is returned.
+pub fn feuerbach_tangent(t: Triangle) -> Result<Line> {
+    let F = feuerbach_point(t)?;
+    Ok(perp(F, Line::from_2p(nine_point(t)?, F)?))
 }
 
 #[inline]
-pub fn gergonne((A, B, C): Triangle) -> Result<Point> {
-    let a = C.distance(B);
-    let b = B.distance(A);
-    let c = A.distance(C);
+pub fn symmedian(t: Triangle) -> Result<Point> {
+    let (a, b, c) = side_lengths(t);
+    from_barycentric(t, (a * a, b * b, c * c))
+}
+
+/// Returns the first and second Lemoine circles of a triangle.
+/// The first passes through the six points where the parallels to the sides through the
+/// symmedian point meet the sides, and the second through the six points where the
+/// antiparallels do. The second is centered at the symmedian point.
+/// If the triangle is degenerate return `CollinearPoints` error.
+pub fn lemoine_circles(t: Triangle) -> Result<(Circle, Circle)> {
+    if area(t) < EPSILON {
+        return Err(CalcException::CollinearPoints);
+    }
+    let (A, B, C) = t;
+    let K = symmedian(t)?;
+    let (AB, BC, CA) = (
+        Line::from_2p(A, B)?,
+        Line::from_2p(B, C)?,
+        Line::from_2p(C, A)?,
+    );
+    let la = parallel(K, BC);
+    let lb = parallel(K, CA);
+    let first = Circle::from_3p(la.inter(AB)?, la.inter(CA)?, lb.inter(AB)?)?;
+    let (a, b, c) = side_lengths(t);
+    let second = Circle {
+        O: K,
+        r: a * b * c / (a * a + b * b + c * c),
+    };
+    Ok((first, second))
+}
+
+#[inline]
+pub fn gergonne(t: Triangle) -> Result<Point> {
+    let (a, b, c) = side_lengths(t);
     let p = (a + b + c) / 2.0;
-    from_barycentric((A, B, C), (p - a, p - b, p - c))
+    let (x, y, z) = (p - a, p - b, p - c);
+    from_barycentric(t, (y * z, z * x, x * y))
+}
+
+/// Returns the Nagel point, with barycentric coordinates `(s - a : s - b : s - c)`.
+#[inline]
+pub fn nagel_point(t: Triangle) -> Result<Point> {
+    let (a, b, c) = side_lengths(t);
+    let p = (a + b + c) / 2.0;
+    from_barycentric(t, (p - a, p - b, p - c))
+}
+
+/// Returns the Spieker center, the incenter of the medial triangle, with barycentric
+/// coordinates `(b + c : c + a : a + b)`.
+#[inline]
+pub fn spieker_center(t: Triangle) -> Result<Point> {
+    let (a, b, c) = side_lengths(t);
+    from_barycentric(t, (b + c, c + a, a + b))
+}
+
+/// Returns the mittenpunkt, the symmedian point of the excentral triangle, with barycentric
+/// coordinates `(a(s - a) : b(s - b) : c(s - c))`.
+#[inline]
+pub fn mittenpunkt(t: Triangle) -> Result<Point> {
+    let (a, b, c) = side_lengths(t);
+    let p = (a + b + c) / 2.0;
+    from_barycentric(t, (a * (p - a), b * (p - b), c * (p - c)))
+}
+
+/// Returns the Fermat point of a triangle, which minimizes the total distance to the
+/// three vertices.
+/// If an angle is at least `2pi / 3` this is that vertex, otherwise it is the common point
+/// of the lines joining each vertex to the apex of the equilateral triangle erected
+/// outwards on the opposite side.
+pub fn fermat_point(t: Triangle) -> Result<Point> {
+    let (A, B, C) = t;
+    let (a, b, c) = angles(t)?;
+    let limit = 2.0 * std::f64::consts::FRAC_PI_3 - EPSILON;
+    if a >= limit {
+        return Ok(A);
+    } else if b >= limit {
+        return Ok(B);
+    } else if c >= limit {
+        return Ok(C);
+    }
+    let angle = -orientation(A, B, C) as f64 * std::f64::consts::FRAC_PI_3;
+    let P = C.rotate(B, angle);
+    let Q = A.rotate(C, angle);
+    Line::from_2p(A, P)?.inter(Line::from_2p(B, Q)?)
 }
@@ -0,0 +1,128 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    calc::{
+        basic::{orientation, Distance, Intersect},
+        constants::EPSILON,
+        construct::{midpoint, polar_line, projection},
+        exception::{CalcException, Result},
+        point_on::PointOn,
+        transform::Rotate,
+    },
+    objects::{Line, Point},
+};
+
+use super::{
+    centers::{centroid, circumcircle},
+    Triangle,
+};
+
+/// Returns the medial triangle, whose vertices are the midpoints of `BC`, `CA`, `AB`.
+#[inline]
+pub fn medial((A, B, C): Triangle) -> Triangle {
+    (midpoint(B, C), midpoint(C, A), midpoint(A, B))
+}
+
+/// Returns the orthic triangle, whose vertices are the feet of the altitudes from
+/// `A`, `B`, `C`.
+/// For a right triangle two of the feet coincide with the right-angled vertex, and
+/// they are returned as-is.
+pub fn orthic((A, B, C): Triangle) -> Result<Triangle> {
+    Ok((
+        projection(A, Line::from_2p(B, C)?),
+        projection(B, Line::from_2p(C, A)?),
+        projection(C, Line::from_2p(A, B)?),
+    ))
+}
+
+/// Returns the tangent to the circumcircle at the vertex `A`, `B` or `C`, chosen by
+/// `vertex` being `0`, `1` or `2`.
+/// If `vertex` is not one of them return `IndexOutOfRange` error.
+pub fn circumcircle_tangent_at_vertex(t: Triangle, vertex: usize) -> Result<Line> {
+    let P = match vertex {
+        0 => t.0,
+        1 => t.1,
+        2 => t.2,
+        _ => return Err(CalcException::IndexOutOfRange),
+    };
+    polar_line(P, circumcircle(t)?)
+}
+
+/// Returns the tangential triangle, formed by the tangents to the circumcircle at the
+/// vertices. Its vertices are opposite to `A`, `B`, `C` respectively.
+/// If two tangents are parallel (a right triangle) return `NoIntersection` error.
+pub fn tangential_triangle(t: Triangle) -> Result<Triangle> {
+    let c = circumcircle(t)?;
+    let la = polar_line(t.0, c)?;
+    let lb = polar_line(t.1, c)?;
+    let lc = polar_line(t.2, c)?;
+    Ok((lb.inter(lc)?, lc.inter(la)?, la.inter(lb)?))
+}
+
+/// Returns the pedal triangle of a point, whose vertices are the feet of the perpendiculars
+/// from `P` to `BC`, `CA`, `AB`. The orthic triangle is the pedal triangle of the
+/// orthocenter.
+pub fn pedal_triangle((A, B, C): Triangle, P: Point) -> Result<Triangle> {
+    Ok((
+        projection(P, Line::from_2p(B, C)?),
+        projection(P, Line::from_2p(C, A)?),
+        projection(P, Line::from_2p(A, B)?),
+    ))
+}
+
+/// Returns the Simson line of a point on the circumcircle, through the (collinear) feet of
+/// the perpendiculars from `P` to the sides. The two feet farthest apart are used, so that
+/// it is well-defined when `P` is a vertex.
+/// If `P` is not on the circumcircle the feet are not collinear, and return
+/// `CollinearPoints` error.
+pub fn simson_line(t: Triangle, P: Point) -> Result<Line> {
+    let c = circumcircle(t)?;
+    if (P.distance(c.O) - c.r).abs() >= EPSILON * c.r.max(1.0) {
+        return Err(CalcException::CollinearPoints);
+    }
+    simson(t, P)
+}
+
+/// The line through the two feet of the perpendiculars from `P` farthest apart, without
+/// checking that `P` is on the circumcircle.
+fn simson(t: Triangle, P: Point) -> Result<Line> {
+    let (D, E, F) = pedal_triangle(t, P)?;
+    let (X, Y) = [(D, E), (E, F), (F, D)]
+        .into_iter()
+        .max_by(|(X, Y), (Z, W)| X.distance_sq(*Y).total_cmp(&Z.distance_sq(*W)))
+        .unwrap();
+    Line::from_2p(X, Y)
+}
+
+/// Returns `n` points sampled on the Steiner deltoid, the envelope of the Simson lines of
+/// the points on the circumcircle.
+/// Each sample is the intersection of the Simson lines of two nearby points on the
+/// circumcircle.
+pub fn steiner_deltoid_points(t: Triangle, n: usize) -> Result<Vec<Point>> {
+    const H: f64 = 1e-4;
+    let c = circumcircle(t)?;
+    (0..n)
+        .map(|i| {
+            let theta = std::f64::consts::TAU * i as f64 / n as f64;
+            let l = simson(t, c.point_on(theta - H))?;
+            let k = simson(t, c.point_on(theta + H))?;
+            l.inter(k)
+        })
+        .collect()
+}
+
+/// Returns the Napoleon triangle, formed by the centers of the equilateral triangles
+/// erected on `BC`, `CA`, `AB`, outwards if `outward` is true and inwards otherwise.
+/// If the triangle is degenerate return `CollinearPoints` error.
+pub fn napoleon_triangle((A, B, C): Triangle, outward: bool) -> Result<Triangle> {
+    let s = orientation(A, B, C);
+    if s == 0 {
+        return Err(CalcException::CollinearPoints);
+    }
+    let angle = if outward { -1.0 } else { 1.0 } * s as f64 * std::f64::consts::FRAC_PI_3;
+    Ok((
+        centroid((B, C, C.rotate(B, angle))),
+        centroid((C, A, A.rotate(C, angle))),
+        centroid((A, B, B.rotate(A, angle))),
+    ))
+}
@@ -0,0 +1,39 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    calc::construct::projection,
+    objects::{Line, Point},
+};
+
+pub mod svg;
+pub mod tikz;
+
+/// Clip a Line to the rectangle `(x, y, width, height)`, returning the endpoints of the
+/// visible part, or `None` if the Line misses the rectangle.
+pub(crate) fn clip_line(l: Line, (x, y, w, h): (f64, f64, f64, f64)) -> Option<(Point, Point)> {
+    let P = projection(
+        Point {
+            x: x + w / 2.0,
+            y: y + h / 2.0,
+        },
+        l,
+    );
+    let d = Point { x: -l.b, y: l.a };
+    let (mut lo, mut hi) = (f64::NEG_INFINITY, f64::INFINITY);
+    for (p, d, min, max) in [(P.x, d.x, x, x + w), (P.y, d.y, y, y + h)] {
+        if d == 0.0 {
+            if p < min || p > max {
+                return None;
+            }
+        } else {
+            let (t1, t2) = ((min - p) / d, (max - p) / d);
+            lo = lo.max(t1.min(t2));
+            hi = hi.min(t1.max(t2));
+        }
+    }
+    if lo > hi {
+        None
+    } else {
+        Some((P + d * lo, P + d * hi))
+    }
+}
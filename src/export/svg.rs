@@ -0,0 +1,64 @@
+#![allow(non_snake_case)]
+
+use std::fmt::Write;
+
+use crate::objects::{Circle, Line, Point};
+
+use super::clip_line;
+
+/// Render a scene as a complete SVG document.
+/// The `viewbox` is `(x, y, width, height)` in the coordinates of the objects, whose
+/// y-axis points upwards as usual. Lines are clipped to the viewbox, and points are drawn
+/// as small filled circles.
+pub fn to_svg(
+    points: &[Point],
+    lines: &[Line],
+    circles: &[Circle],
+    viewbox: (f64, f64, f64, f64),
+) -> String {
+    let (x, y, w, h) = viewbox;
+    let dot = w.max(h) / 200.0;
+    let mut s = String::new();
+    writeln!(
+        s,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+        x,
+        -y - h,
+        w,
+        h
+    )
+    .unwrap();
+    writeln!(
+        s,
+        r#"<g transform="scale(1 -1)" fill="none" stroke="black" stroke-width="1">"#
+    )
+    .unwrap();
+    for &l in lines {
+        if let Some((P, Q)) = clip_line(l, viewbox) {
+            writeln!(
+                s,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" vector-effect="non-scaling-stroke"/>"#,
+                P.x, P.y, Q.x, Q.y
+            )
+            .unwrap();
+        }
+    }
+    for c in circles {
+        writeln!(
+            s,
+            r#"<circle cx="{}" cy="{}" r="{}" vector-effect="non-scaling-stroke"/>"#,
+            c.O.x, c.O.y, c.r
+        )
+        .unwrap();
+    }
+    for P in points {
+        writeln!(
+            s,
+            r#"<circle cx="{}" cy="{}" r="{}" fill="black" stroke="none"/>"#,
+            P.x, P.y, dot
+        )
+        .unwrap();
+    }
+    s.push_str("</g>\n</svg>\n");
+    s
+}
@@ -0,0 +1,40 @@
+#![allow(non_snake_case)]
+
+use std::fmt::Write;
+
+use crate::objects::{Circle, Line, Point};
+
+use super::clip_line;
+
+/// Render a scene as a TikZ `tikzpicture` environment.
+/// Each point is drawn as a dot labeled with its name. Lines are clipped to the bounding
+/// box of the points, enlarged by a margin, so no lines are drawn if there are no points.
+pub fn to_tikz(points: &[(Point, &str)], lines: &[Line], circles: &[Circle]) -> String {
+    let mut s = String::from("\\begin{tikzpicture}\n");
+    if let Some(&(P, _)) = points.first() {
+        let (lo, hi) = points
+            .iter()
+            .fold((P, P), |(lo, hi), &(Q, _)| (lo.min(Q), hi.max(Q)));
+        let margin = (hi.x - lo.x).max(hi.y - lo.y).max(1.0) / 10.0;
+        let bbox = (
+            lo.x - margin,
+            lo.y - margin,
+            hi.x - lo.x + 2.0 * margin,
+            hi.y - lo.y + 2.0 * margin,
+        );
+        for &l in lines {
+            if let Some((P, Q)) = clip_line(l, bbox) {
+                writeln!(s, "\\draw ({}, {}) -- ({}, {});", P.x, P.y, Q.x, Q.y).unwrap();
+            }
+        }
+    }
+    for c in circles {
+        writeln!(s, "\\draw ({}, {}) circle ({});", c.O.x, c.O.y, c.r).unwrap();
+    }
+    for (P, name) in points {
+        writeln!(s, "\\fill ({}, {}) circle (1pt);", P.x, P.y).unwrap();
+        writeln!(s, "\\node[above right] at ({}, {}) {{{}}};", P.x, P.y, name).unwrap();
+    }
+    s.push_str("\\end{tikzpicture}\n");
+    s
+}
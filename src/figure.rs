@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+
+use crate::objects::{Circle, Line, Point, Segment};
+
+/// An object recorded as a step of a Figure.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Primitive {
+    Point(Point),
+    Line(Line),
+    Circle(Circle),
+    Segment(Segment),
+}
+
+impl From<Point> for Primitive {
+    #[inline]
+    fn from(P: Point) -> Self {
+        Primitive::Point(P)
+    }
+}
+
+impl From<Line> for Primitive {
+    #[inline]
+    fn from(l: Line) -> Self {
+        Primitive::Line(l)
+    }
+}
+
+impl From<Circle> for Primitive {
+    #[inline]
+    fn from(c: Circle) -> Self {
+        Primitive::Circle(c)
+    }
+}
+
+impl From<Segment> for Primitive {
+    #[inline]
+    fn from(s: Segment) -> Self {
+        Primitive::Segment(s)
+    }
+}
+
+/// A Figure, recording each construction as a step so that it can be reverted.
+/// Steps form a linear chain: undoing a step does not recompute the steps derived from it,
+/// it only removes the last one.
+#[derive(Debug, Clone, Default)]
+pub struct Figure {
+    steps: Vec<Primitive>,
+}
+
+impl Figure {
+    #[inline]
+    pub fn new() -> Self {
+        Figure { steps: Vec::new() }
+    }
+    /// Record a new step, returning its index.
+    #[inline]
+    pub fn push_step(&mut self, obj: impl Into<Primitive>) -> usize {
+        self.steps.push(obj.into());
+        self.steps.len() - 1
+    }
+    /// Revert the last step, returning it.
+    #[inline]
+    pub fn undo(&mut self) -> Option<Primitive> {
+        self.steps.pop()
+    }
+    /// The step at index `i`.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<Primitive> {
+        self.steps.get(i).copied()
+    }
+    /// All recorded steps, in order.
+    #[inline]
+    pub fn steps(&self) -> &[Primitive] {
+        &self.steps
+    }
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
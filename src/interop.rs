@@ -0,0 +1,5 @@
+//! Conversions to and from the types of other crates, each behind the feature of the same
+//! name.
+
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
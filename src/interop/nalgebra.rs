@@ -0,0 +1,76 @@
+#![allow(non_snake_case)]
+
+use ::nalgebra::{Affine2, Matrix3, Point2};
+
+use crate::{
+    calc::{constants::EPSILON, exception::CalcException, transform::Affine},
+    objects::Point,
+};
+
+impl From<Point> for Point2<f64> {
+    #[inline]
+    fn from(P: Point) -> Self {
+        Point2::new(P.x, P.y)
+    }
+}
+
+impl From<Point2<f64>> for Point {
+    #[inline]
+    fn from(P: Point2<f64>) -> Self {
+        Point { x: P.x, y: P.y }
+    }
+}
+
+impl From<Affine> for Matrix3<f64> {
+    /// The homogeneous matrix of the transformation, with last row `(0, 0, 1)`.
+    #[inline]
+    fn from(f: Affine) -> Self {
+        let m = f.m;
+        Matrix3::new(
+            m[0][0], m[0][1], f.t.x, //
+            m[1][0], m[1][1], f.t.y, //
+            0.0, 0.0, 1.0,
+        )
+    }
+}
+
+impl TryFrom<Matrix3<f64>> for Affine {
+    type Error = CalcException;
+    /// The transformation of a homogeneous matrix. If its last row is not `(0, 0, 1)`
+    /// return `NotAffine` error.
+    fn try_from(m: Matrix3<f64>) -> Result<Self, Self::Error> {
+        let last = [m[(2, 0)], m[(2, 1)], m[(2, 2)] - 1.0];
+        if last.iter().any(|x| x.abs() >= EPSILON) {
+            return Err(CalcException::NotAffine);
+        }
+        Ok(Affine {
+            m: [[m[(0, 0)], m[(0, 1)]], [m[(1, 0)], m[(1, 1)]]],
+            t: Point {
+                x: m[(0, 2)],
+                y: m[(1, 2)],
+            },
+        })
+    }
+}
+
+impl From<Affine> for Affine2<f64> {
+    /// The transformation should be invertible, as `Affine2` assumes.
+    #[inline]
+    fn from(f: Affine) -> Self {
+        Affine2::from_matrix_unchecked(f.into())
+    }
+}
+
+impl From<Affine2<f64>> for Affine {
+    #[inline]
+    fn from(f: Affine2<f64>) -> Self {
+        let m = f.matrix();
+        Affine {
+            m: [[m[(0, 0)], m[(0, 1)]], [m[(1, 0)], m[(1, 1)]]],
+            t: Point {
+                x: m[(0, 2)],
+                y: m[(1, 2)],
+            },
+        }
+    }
+}
@@ -1,2 +1,5 @@
 pub mod objects;
 pub mod calc;
+pub mod export;
+pub mod figure;
+pub mod interop;
@@ -1,24 +1,156 @@
 #![allow(non_snake_case)]
 
+use std::str::FromStr;
+
+use crate::calc::scalar::Scalar;
+
+#[cfg(feature = "complex")]
+use num_complex::Complex;
 #[cfg(feature = "serialize")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+/// Errors that can occur when parsing an object from a string.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseObjectError {
+    /// The string is not in the format produced by `Display`
+    Malformed,
+    /// Parsing a circle with nonpositive radius
+    NonpositiveRadius,
+    /// Parsing a line with coefficients `a` and `b` both zero
+    ZeroCoefficient,
+}
+
+impl std::fmt::Display for ParseObjectError {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseObjectError::Malformed => {
+                write!(f, "The string is not in the format produced by `Display`")
+            }
+            ParseObjectError::NonpositiveRadius => {
+                write!(f, "Parsing a circle with nonpositive radius")
+            }
+            ParseObjectError::ZeroCoefficient => {
+                write!(f, "Parsing a line with coefficients `a` and `b` both zero")
+            }
+        }
+    }
+}
+
+/// Parse a float, surrounded by optional whitespace.
+#[inline]
+fn parse_f64(s: &str) -> Result<f64, ParseObjectError> {
+    s.trim().parse().map_err(|_| ParseObjectError::Malformed)
+}
+
+/// A value displayed with the precision of an outer formatter, if it has one, so that
+/// `{:.3}` applies to every coordinate of an object.
+struct WithPrecision<T>(T, Option<usize>);
+
+impl<T: std::fmt::Display> std::fmt::Display for WithPrecision<T> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.1 {
+            Some(p) => write!(f, "{:.*}", p, self.0),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
 
 /// A struct representing a Point.
-#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy)]
-pub struct Point {
-    pub x: f64,
-    pub y: f64,
+pub struct Point<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
-impl std::fmt::Display for Point {
+impl<T: std::fmt::Display + Copy> std::fmt::Display for Point<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "( {} , {} )", self.x, self.y)
+        let p = f.precision();
+        let (x, y) = (WithPrecision(self.x, p), WithPrecision(self.y, p));
+        write!(f, "( {} , {} )", x, y)
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseObjectError;
+
+    /// Parse a Point from `(x, y)`, the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or(ParseObjectError::Malformed)?;
+        let (x, y) = s.split_once(',').ok_or(ParseObjectError::Malformed)?;
+        Ok(Point {
+            x: parse_f64(x)?,
+            y: parse_f64(y)?,
+        })
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    #[inline]
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    #[inline]
+    fn from(P: Point<T>) -> Self {
+        (P.x, P.y)
+    }
+}
+
+impl<T> From<[T; 2]> for Point<T> {
+    #[inline]
+    fn from([x, y]: [T; 2]) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for [T; 2] {
+    #[inline]
+    fn from(P: Point<T>) -> Self {
+        [P.x, P.y]
+    }
+}
+
+#[cfg(feature = "complex")]
+impl Point {
+    /// The Point as the complex number `x + yi`.
+    #[inline]
+    pub fn to_complex(self) -> Complex<f64> {
+        Complex::new(self.x, self.y)
+    }
+    /// The Point of the complex number `x + yi`.
+    #[inline]
+    pub fn from_complex(z: Complex<f64>) -> Self {
+        Point { x: z.re, y: z.im }
+    }
+}
+
+#[cfg(feature = "complex")]
+impl From<Complex<f64>> for Point {
+    #[inline]
+    fn from(z: Complex<f64>) -> Self {
+        Point::from_complex(z)
+    }
+}
+
+#[cfg(feature = "complex")]
+impl From<Point> for Complex<f64> {
+    #[inline]
+    fn from(P: Point) -> Self {
+        P.to_complex()
     }
 }
 
-impl std::ops::Add for Point {
-    type Output = Point;
+impl<T: Scalar> std::ops::Add for Point<T> {
+    type Output = Point<T>;
 
     /// Perform vector addition.
     #[inline]
@@ -30,8 +162,8 @@ impl std::ops::Add for Point {
     }
 }
 
-impl std::ops::Sub for Point {
-    type Output = Point;
+impl<T: Scalar> std::ops::Sub for Point<T> {
+    type Output = Point<T>;
 
     /// Perform vector subtraction.
     #[inline]
@@ -43,12 +175,12 @@ impl std::ops::Sub for Point {
     }
 }
 
-impl std::ops::Mul<f64> for Point {
-    type Output = Point;
+impl<T: Scalar> std::ops::Mul<T> for Point<T> {
+    type Output = Point<T>;
 
     /// Perform scalar multiplication.
     #[inline]
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Point {
             x: self.x * rhs,
             y: self.y * rhs,
@@ -56,12 +188,12 @@ impl std::ops::Mul<f64> for Point {
     }
 }
 
-impl std::ops::Div<f64> for Point {
-    type Output = Point;
+impl<T: Scalar> std::ops::Div<T> for Point<T> {
+    type Output = Point<T>;
 
     /// Perform scalar division.
     #[inline]
-    fn div(self, rhs: f64) -> Self::Output {
+    fn div(self, rhs: T) -> Self::Output {
         Point {
             x: self.x / rhs,
             y: self.y / rhs,
@@ -69,31 +201,237 @@ impl std::ops::Div<f64> for Point {
     }
 }
 
+impl<T: Scalar> std::ops::Neg for Point<T> {
+    type Output = Point<T>;
+
+    /// Perform vector negation.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: Scalar> std::ops::AddAssign for Point<T> {
+    /// Perform vector addition in place.
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Scalar> std::ops::SubAssign for Point<T> {
+    /// Perform vector subtraction in place.
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Scalar> std::ops::MulAssign<T> for Point<T> {
+    /// Perform scalar multiplication in place.
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl<T: Scalar> std::ops::DivAssign<T> for Point<T> {
+    /// Perform scalar division in place.
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
 /// A struct representing a Line, by its standard form `Ax + By + C = 0`.
-#[cfg_attr(feature = "serialize", derive(Serialize))]
+/// Deserializing a Line does not check that `A` and `B` are not both zero, as
+/// `Line::from_coeff` does.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy)]
-pub struct Line {
-    pub a: f64,
-    pub b: f64,
-    pub c: f64,
+pub struct Line<T = f64> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
 }
 
-impl std::fmt::Display for Line {
+impl<T: std::fmt::Display + Copy> std::fmt::Display for Line<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}x + {}y + {}", self.a, self.b, self.c)
+        let p = f.precision();
+        let [a, b, c] = [self.a, self.b, self.c].map(|z| WithPrecision(z, p));
+        write!(f, "{}x + {}y + {}", a, b, c)
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseObjectError;
+
+    /// Parse a Line from `ax + by + c`, the format produced by `Display`.
+    /// As with `Line::from_coeff`, `a` and `b` cannot be both zero.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, s) = s.split_once('x').ok_or(ParseObjectError::Malformed)?;
+        let s = s
+            .trim_start()
+            .strip_prefix('+')
+            .ok_or(ParseObjectError::Malformed)?;
+        let (b, s) = s.split_once('y').ok_or(ParseObjectError::Malformed)?;
+        let c = s
+            .trim_start()
+            .strip_prefix('+')
+            .ok_or(ParseObjectError::Malformed)?;
+        Line::from_coeff(parse_f64(a)?, parse_f64(b)?, parse_f64(c)?)
+            .map_err(|_| ParseObjectError::ZeroCoefficient)
     }
 }
 
 /// A struct representing a Circle, by its center and radius.
-#[cfg_attr(feature = "serialize", derive(Serialize))]
+/// Deserializing a Circle does not check that its radius is positive, as
+/// `Circle::from_center_radius` does.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Circle<T = f64> {
+    pub O: Point<T>,
+    pub r: T,
+}
+
+impl<T: std::fmt::Display + Copy> std::fmt::Display for Circle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let p = f.precision();
+        write!(
+            f,
+            "circ({}, {})",
+            WithPrecision(self.O, p),
+            WithPrecision(self.r, p)
+        )
+    }
+}
+
+impl FromStr for Circle {
+    type Err = ParseObjectError;
+
+    /// Parse a Circle from `circ((x, y), r)`, the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix("circ(")
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or(ParseObjectError::Malformed)?;
+        let (O, r) = s.rsplit_once(',').ok_or(ParseObjectError::Malformed)?;
+        let r = parse_f64(r)?;
+        if r <= 0.0 {
+            return Err(ParseObjectError::NonpositiveRadius);
+        }
+        Ok(Circle { O: O.parse()?, r })
+    }
+}
+
+/// A struct representing a Segment, by its two endpoints.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy)]
-pub struct Circle {
+pub struct Segment {
+    pub A: Point,
+    pub B: Point,
+}
+
+impl std::fmt::Display for Segment {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let p = f.precision();
+        write!(
+            f,
+            "seg({}, {})",
+            WithPrecision(self.A, p),
+            WithPrecision(self.B, p)
+        )
+    }
+}
+
+/// A struct representing a Polygon, by its vertices in order.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Polygon(pub Vec<Point>);
+
+impl std::fmt::Display for Polygon {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "poly(")?;
+        for (i, P) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", WithPrecision(P, f.precision()))?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// An axis-aligned bounding box, by its lower-left and upper-right corners.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct AABB {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl std::fmt::Display for AABB {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let p = f.precision();
+        write!(
+            f,
+            "box({}, {})",
+            WithPrecision(self.min, p),
+            WithPrecision(self.max, p)
+        )
+    }
+}
+
+/// A struct representing an Ellipse, by its center `O`, its semi-axes `a` and `b`, and the
+/// counterclockwise angle from the `x`-axis to the `a` axis.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Ellipse {
     pub O: Point,
-    pub r: f64,
+    pub a: f64,
+    pub b: f64,
+    pub angle: f64,
 }
 
-impl std::fmt::Display for Circle {
+impl std::fmt::Display for Ellipse {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "circ({}, {})", self.O, self.r)
+        let p = f.precision();
+        let [a, b, angle] = [self.a, self.b, self.angle].map(|z| WithPrecision(z, p));
+        write!(
+            f,
+            "ellipse({}, {}, {}, {})",
+            WithPrecision(self.O, p),
+            a,
+            b,
+            angle
+        )
+    }
+}
+
+/// A struct representing a Conic, by its standard form `Ax^2 + Bxy + Cy^2 + Dx + Ey + F = 0`.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Conic {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl std::fmt::Display for Conic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let p = f.precision();
+        let [a, b, c, d, e, g] =
+            [self.a, self.b, self.c, self.d, self.e, self.f].map(|z| WithPrecision(z, p));
+        write!(f, "{}x^2 + {}xy + {}y^2 + {}x + {}y + {}", a, b, c, d, e, g)
     }
 }
@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 
 use metric_rs::{
-    calc::{basic::*, exception::CalcException},
+    calc::{basic::*, constants::EPSILON, exception::CalcException, transform::Rotate},
     objects::*,
 };
 
@@ -32,7 +32,11 @@ fn objects_def() {
     );
     assert_eq!(
         Circle::from_3p(A, O, D).unwrap_err(),
-        CalcException::NoIntersection
+        CalcException::CollinearPoints
+    );
+    assert_eq!(
+        Circle::from_3p(A, D, A).unwrap_err(),
+        CalcException::OverlappingPoint
     );
     assert_eq!(
         Circle::from_center_radius(O, 0.0).unwrap_err(),
@@ -67,3 +71,516 @@ fn intersect() {
     assert_eq!(S.x, T.x);
     assert_eq!(S.x, 1.8);
 }
+
+#[test]
+fn circle_distance() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(5.0, 0.0);
+    let c = Circle::from_center_radius(A, 1.0).unwrap();
+    let d = Circle::from_center_radius(B, 2.0).unwrap();
+    let e = Circle::from_center_radius(Point::new(0.5, 0.0), 3.0).unwrap();
+    let f = Circle::from_center_radius(B, 4.5).unwrap();
+    // Separate.
+    assert_eq!(c.distance(d), 2.0);
+    // Nested.
+    assert_eq!(c.distance(e), 1.5);
+    assert_eq!(e.distance(c), 1.5);
+    // Intersecting.
+    assert_eq!(c.distance(f), 0.0);
+    let l = Line::from_coeff(1.0, 0.0, -3.0).unwrap();
+    assert_eq!(c.distance(l), 2.0);
+    assert_eq!(d.distance(l), 0.0);
+}
+
+#[test]
+fn vector_products() {
+    let u = Point::new(3.0, 4.0);
+    let v = Point::new(-8.0, 6.0);
+    assert_eq!(u.norm(), 5.0);
+    assert_eq!(v.norm_sq(), 100.0);
+    assert!(u.dot(v).abs() < EPSILON);
+    assert_eq!(u.cross(v), 50.0);
+    assert_eq!(v.cross(u), -50.0);
+    let w = Point::new(0.1, 0.2) + Point::new(0.2, 0.1);
+    assert!(w.dot(Point::new(1.0, -1.0)).abs() < EPSILON);
+}
+
+#[test]
+fn orientations() {
+    let A = Point::new(0.0, 0.0);
+    let C = Point::new(10.0, 24.0);
+    let D = Point::new(100.0, 240.0);
+    let E = Point::new(10.0, 24.000001);
+    let F = Point::new(10.0, 24.0 + 1e-12);
+    assert_eq!(orientation(A, C, D), 0);
+    assert_eq!(orientation(A, C, E), 1);
+    assert_eq!(orientation(A, E, C), -1);
+    assert_eq!(orientation(A, F, C), 0);
+    assert_eq!(orientation(E, A, C), 1);
+}
+
+#[test]
+fn containment() {
+    let c = Circle::from_center_radius(Point::new(1.0, 1.0), 5.0).unwrap();
+    assert!(c.contains(Point::new(4.0, 4.0)));
+    assert!(!c.contains(Point::new(4.0, 5.0)));
+    assert!(c.on_boundary(Point::new(4.0, 5.0)));
+    assert!(!c.contains(Point::new(7.0, 1.0)));
+    assert!(!c.on_boundary(Point::new(7.0, 1.0)));
+}
+
+#[test]
+fn component_wise() {
+    let P = Point::new(-3.0, 2.0);
+    let Q = Point::new(1.0, -5.0);
+    assert!(P.min(Q) == Point::new(-3.0, -5.0));
+    assert!(P.max(Q) == Point::new(1.0, 2.0));
+    let lo = Point::new(-1.0, -1.0);
+    let hi = Point::new(1.0, 1.0);
+    assert!(P.clamp(lo, hi) == Point::new(-1.0, 1.0));
+    assert!(Q.clamp(lo, hi) == Point::new(1.0, -1.0));
+    assert!(Point::new(0.5, -0.5).clamp(lo, hi) == Point::new(0.5, -0.5));
+}
+
+#[test]
+fn point_conversions() {
+    let P: Point = (3.0, 4.0).into();
+    assert_eq!(P, Point::new(3.0, 4.0));
+    assert_eq!(<(f64, f64)>::from(P), (3.0, 4.0));
+    let Q = Point::from([-1.5, 2.0]);
+    assert_eq!(Q, Point::new(-1.5, 2.0));
+    let a: [f64; 2] = Q.into();
+    assert_eq!(a, [-1.5, 2.0]);
+}
+
+#[test]
+fn point_operators() {
+    let P = Point::new(3.0, -4.0);
+    let Q = Point::new(0.5, 2.0);
+    assert_eq!(-P, P * -1.0);
+    let mut R = P;
+    R += Q;
+    assert_eq!(R, P + Q);
+    R -= Q;
+    assert_eq!(R, P);
+    R *= 2.5;
+    assert_eq!(R, P * 2.5);
+    R /= 2.5;
+    assert_eq!(R, P);
+}
+
+#[test]
+fn display_precision() {
+    let P = Point::new(1.0 / 3.0, 2.0 / 3.0);
+    assert_eq!(format!("{:.2}", P), "( 0.33 , 0.67 )");
+    assert_eq!(format!("{}", Point::new(1.5, -2.0)), "( 1.5 , -2 )");
+    let c = Circle::from_center_radius(P, 2f64.sqrt()).unwrap();
+    assert_eq!(format!("{:.3}", c), "circ(( 0.333 , 0.667 ), 1.414)");
+    let l = Line::from_coeff(0.5, 1.0 / 7.0, -1.0).unwrap();
+    assert_eq!(format!("{:.1}", l), "0.5x + 0.1y + -1.0");
+}
+
+#[test]
+fn parse_objects() {
+    let P = Point::new(1.0 / 3.0, -2.5e-7);
+    assert_eq!(P.to_string().parse(), Ok(P));
+    assert_eq!("(1, 2)".parse(), Ok(Point::new(1.0, 2.0)));
+    let l = Line::from_2p(P, Point::new(4.0, 1e5)).unwrap();
+    assert_eq!(l.to_string().parse(), Ok(l));
+    assert_eq!(
+        "1x + -2y + 3".parse(),
+        Ok(Line::from_coeff(1.0, -2.0, 3.0).unwrap())
+    );
+    let c = Circle::from_center_radius(P, 0.75).unwrap();
+    assert_eq!(c.to_string().parse(), Ok(c));
+    assert_eq!(
+        "circ((0, 0), -1)".parse::<Circle>(),
+        Err(ParseObjectError::NonpositiveRadius)
+    );
+    assert_eq!("(1; 2)".parse::<Point>(), Err(ParseObjectError::Malformed));
+    assert_eq!(
+        "1x + 2z + 3".parse::<Line>(),
+        Err(ParseObjectError::Malformed)
+    );
+    assert_eq!(
+        "0x + 0y + 1".parse::<Line>(),
+        Err(ParseObjectError::ZeroCoefficient)
+    );
+    assert_eq!(
+        "circ((0, 0) 1)".parse::<Circle>(),
+        Err(ParseObjectError::Malformed)
+    );
+}
+
+#[test]
+fn tolerance() {
+    let P = Point::new(1.0, 2.0);
+    let Q = Point::new(1.0 + 1e-8, 2.0);
+    assert!(P != Q);
+    assert!(!P.approx_eq_within(Q, Tolerance::default()));
+    assert!(P.approx_eq_within(Q, Tolerance::new(1e-6)));
+    let R = Point::new(1e9, -3e9);
+    let S = Point::new(1e9 + 1e-3, -3e9);
+    assert!(R != S);
+    assert!(R.approx_eq_within(S, Tolerance::default().with_relative(1e-10)));
+    assert!(!P.approx_eq_within(Q, Tolerance::default().with_relative(1e-10)));
+    let l = Line::from_2p(Point::new(0.0, 0.0), Point::new(1e6, 1e6)).unwrap();
+    let k = Line::from_2p(Point::new(0.0, 1.0), Point::new(1e6, 1e6 + 1.0 + 1e-6)).unwrap();
+    assert!(!is_parallel(l, k));
+    assert!(is_parallel_within(
+        l,
+        k,
+        Tolerance::default().with_relative(1e-9)
+    ));
+}
+
+#[test]
+fn bounding_boxes() {
+    assert!(bounding_box(&[]).is_none());
+    let P = Point::new(1.0, 2.0);
+    let b = bounding_box(&[P]).unwrap();
+    assert!(b.min == P && b.max == P);
+    assert!(b.contains(P));
+    assert!(!b.contains(Point::new(1.0, 2.1)));
+    assert!(b.intersects(&b));
+    let b = bounding_box(&[P, Point::new(-3.0, 5.0), Point::new(0.0, -1.0)]).unwrap();
+    assert!(b.min == Point::new(-3.0, -1.0) && b.max == Point::new(1.0, 5.0));
+    let c = Circle::from_center_radius(Point::new(4.0, 0.0), 2.0).unwrap();
+    let d = c.bounding_box();
+    assert!(d.min == Point::new(2.0, -2.0) && d.max == Point::new(6.0, 2.0));
+    assert!(!b.intersects(&d));
+    assert!(d.intersects(&AABB {
+        min: Point::new(6.0, 2.0),
+        max: Point::new(7.0, 3.0)
+    }));
+    let u = b.union(d);
+    assert!(u.min == Point::new(-3.0, -2.0) && u.max == Point::new(6.0, 5.0));
+    assert!(u.contains(c.O) && u.contains(P));
+}
+
+#[test]
+fn signed_angles() {
+    let O = Point::new(1.0, 1.0);
+    let A = Point::new(3.0, 1.0);
+    let B = Point::new(1.0, 4.0);
+    let t = signed_angle(A, O, B).unwrap();
+    assert!((t - std::f64::consts::FRAC_PI_2).abs() < EPSILON);
+    assert!((signed_angle(B, O, A).unwrap() + t).abs() < EPSILON);
+    let C = Point::new(-1.0, 1.0 - 1e-3);
+    let t = signed_angle(A, O, C).unwrap();
+    assert!(t < 0.0 && (signed_angle(C, O, A).unwrap() + t).abs() < EPSILON);
+    assert!(
+        (signed_angle(A, O, Point::new(-1.0, 1.0)).unwrap() - std::f64::consts::PI).abs() < EPSILON
+    );
+    assert_eq!(
+        signed_angle(A, O, O).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+    let l = Line::from_coeff(1.0, 2.0, 3.0).unwrap();
+    for k in [
+        Line::from_coeff(-3.0, 1.0, 0.0).unwrap(),
+        Line::from_coeff(3.0, -1.0, 5.0).unwrap(),
+        Line::from_coeff(1.0, 1.0, 0.0).unwrap(),
+        Line::from_coeff(2.0, -1.0, 0.0).unwrap(),
+    ] {
+        let t = directed_angle_between(l, k);
+        assert!(t > -std::f64::consts::FRAC_PI_2 && t <= std::f64::consts::FRAC_PI_2);
+        assert!((t.abs() - angle_between(l, k)).abs() < EPSILON);
+        assert!(is_parallel(l.rotate(O, t), k));
+        assert!(
+            (directed_angle_between(k, l) + t).abs() < EPSILON || t == std::f64::consts::FRAC_PI_2
+        );
+    }
+}
+
+#[test]
+fn line_direction_and_normal() {
+    for l in [
+        Line::from_coeff(3.0, -4.0, 2.0).unwrap(),
+        Line::from_coeff(0.0, -2.0, 1.0).unwrap(),
+        Line::from_coeff(-5.0, 0.0, 1.0).unwrap(),
+    ] {
+        let (d, n) = (l.direction(), l.normal());
+        assert!((d.norm() - 1.0).abs() < EPSILON && (n.norm() - 1.0).abs() < EPSILON);
+        assert!(d.dot(n).abs() < EPSILON);
+        let k = l.normalized();
+        assert!((k.a * k.a + k.b * k.b - 1.0).abs() < EPSILON);
+        assert!(k.a > 0.0 || (k.a == 0.0 && k.b > 0.0));
+        assert!(
+            k == (Line {
+                a: -l.a,
+                b: -l.b,
+                c: -l.c
+            })
+            .normalized()
+        );
+        // The same Points are on both.
+        let P = Point::new(l.a, l.b) * (-l.c / (l.a * l.a + l.b * l.b));
+        for Q in [P, P + d * 3.0] {
+            assert!(l.is_through(Q) && k.is_through(Q));
+        }
+        assert!(!k.is_through(P + n));
+        let t = l.inclination();
+        assert!(t > -std::f64::consts::FRAC_PI_2 && t <= std::f64::consts::FRAC_PI_2);
+        assert!((t.tan() * d.x - d.y).abs() < 1e-6 || d.x.abs() < EPSILON);
+    }
+    let l = Line::from_coeff(-5.0, 0.0, 1.0).unwrap();
+    assert_eq!(l.inclination(), std::f64::consts::FRAC_PI_2);
+    let l = Line::from_2p(Point::new(0.0, 0.0), Point::new(1.0, 1.0)).unwrap();
+    assert!((l.inclination() - std::f64::consts::FRAC_PI_4).abs() < EPSILON);
+}
+
+#[test]
+fn antipode_and_arc_midpoint() {
+    let c = Circle::from_center_radius(Point::new(0.0, 0.0), 1.0).unwrap();
+    let P = Point::new(1.0, 0.0);
+    let Q = Point::new(0.0, 1.0);
+    assert!(c.antipode(P) == Point::new(-1.0, 0.0));
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    assert!(c.arc_midpoint(P, Q, false).unwrap() == Point::new(s, s));
+    assert!(c.arc_midpoint(Q, P, false).unwrap() == Point::new(s, s));
+    assert!(c.arc_midpoint(P, Q, true).unwrap() == Point::new(-s, -s));
+    // For a diameter, the minor arc is the counterclockwise one from `P`.
+    assert!(c.arc_midpoint(P, c.antipode(P), false).unwrap() == Q);
+    assert!(c.arc_midpoint(P, c.antipode(P), true).unwrap() == Point::new(0.0, -1.0));
+    assert!(c.arc_midpoint(P, P, false).unwrap() == P);
+    assert!(c.arc_midpoint(P, P, true).unwrap() == c.antipode(P));
+    assert_eq!(
+        c.arc_midpoint(P, Point::new(0.0, 0.5), false).unwrap_err(),
+        CalcException::NoIntersection
+    );
+    // The on-circle check scales with the radius.
+    let c = Circle::from_center_radius(Point::new(3e5, -2e5), 1e4).unwrap();
+    let on = |t: f64| c.O + Point::new(t.cos(), t.sin()) * c.r;
+    let (P, Q) = (on(0.3), on(1.9));
+    assert!(!c.is_through(P));
+    let M = c.arc_midpoint(P, Q, false).unwrap();
+    assert!(M.distance(on(1.1)) / c.r < 1e-10);
+    assert_eq!(
+        c.arc_midpoint(P, c.O + (P - c.O) * 1.001, false)
+            .unwrap_err(),
+        CalcException::NoIntersection
+    );
+}
+
+#[test]
+fn circle_and_segment_metrics() {
+    let c = Circle::from_center_radius(Point::new(1.0, 1.0), 1.0).unwrap();
+    assert!((c.area() - std::f64::consts::PI).abs() < EPSILON);
+    assert!((c.circumference() - std::f64::consts::TAU).abs() < EPSILON);
+    let s = Segment::from_2p(Point::new(1.0, 1.0), Point::new(4.0, 5.0)).unwrap();
+    assert!((s.length() - 5.0).abs() < EPSILON);
+    assert!((s.length_sq() - 25.0).abs() < EPSILON);
+}
+
+#[test]
+fn point_segment_distance() {
+    let s = Segment::from_2p(Point::new(0.0, 0.0), Point::new(4.0, 0.0)).unwrap();
+    // The foot of the perpendicular is inside the Segment.
+    let P = Point::new(1.0, 3.0);
+    assert_eq!(s.closest_point(P), Point::new(1.0, 0.0));
+    assert!((P.distance(s) - 3.0).abs() < EPSILON);
+    assert!((P.distance(s) - P.distance(s.to_line())).abs() < EPSILON);
+    // The foot is beyond `B`, so the nearest point is `B`.
+    let Q = Point::new(7.0, 4.0);
+    assert_eq!(s.closest_point(Q), s.B);
+    assert!((Q.distance(s) - 5.0).abs() < EPSILON);
+    assert!((Q.distance(s.to_line()) - 4.0).abs() < EPSILON);
+    assert_eq!(s.closest_point(Point::new(-2.0, -1.0)), s.A);
+}
+
+#[test]
+fn segment_intersection() {
+    let s = Segment::from_2p(Point::new(0.0, 0.0), Point::new(4.0, 4.0)).unwrap();
+    let t = Segment::from_2p(Point::new(0.0, 4.0), Point::new(4.0, 0.0)).unwrap();
+    assert_eq!(s.inter(t).unwrap(), Point::new(2.0, 2.0));
+    // The supporting Lines meet, but the Segments do not.
+    let u = Segment::from_2p(Point::new(3.0, 0.0), Point::new(5.0, -2.0)).unwrap();
+    assert_eq!(s.inter(u).unwrap_err(), CalcException::NoIntersection);
+    let v = Segment::from_2p(Point::new(1.0, 0.0), Point::new(5.0, 4.0)).unwrap();
+    assert_eq!(s.inter(v).unwrap_err(), CalcException::NoIntersection);
+}
+
+#[test]
+fn intersection_order() {
+    let line = |a: f64, b: f64, c: f64| Line::from_coeff(a, b, c).unwrap();
+    let circle = |x: f64, y: f64, r: f64| Circle::from_center_radius(Point::new(x, y), r).unwrap();
+    // Centers both on and off the diagonal `y = x`.
+    for c in [circle(1.0, 1.0, 2.0), circle(2.0, -1.0, 3.0)] {
+        // The same Lines, scaled and with flipped normals.
+        for (l, k) in [
+            (line(1.0, -1.0, 0.5), line(-3.0, 3.0, -1.5)),
+            (line(0.0, 2.0, -3.0), line(0.0, -1.0, 1.5)),
+            (line(1.0, 0.0, -1.5), line(-0.5, 0.0, 0.75)),
+        ] {
+            let (P, Q) = l.inter(c).unwrap();
+            let (R, S) = k.inter(c).unwrap();
+            assert!(P == R && Q == S);
+            assert!(c.is_through(P) && c.is_through(Q) && l.is_through(P) && l.is_through(Q));
+            assert!(P.x < Q.x - EPSILON || (P.x - Q.x).abs() < EPSILON && P.y < Q.y);
+            let (U, V) = c.inter(k).unwrap();
+            assert!(P == U && Q == V);
+        }
+    }
+    for (c, d) in [
+        (circle(1.0, 1.0, 2.0), circle(2.0, 0.0, 2.0)),
+        (circle(2.0, -1.0, 3.0), circle(4.0, 1.0, 2.0)),
+    ] {
+        let (P, Q) = c.inter(d).unwrap();
+        let (R, S) = d.inter(c).unwrap();
+        assert!(P == R && Q == S);
+        assert!(c.is_through(P) && c.is_through(Q) && d.is_through(P) && d.is_through(Q));
+        // `inter_common` keeps the common point last.
+        let (R, S) = c.inter_common(d, P).unwrap();
+        assert!(R == Q && S == P);
+    }
+}
+
+#[test]
+fn horizontal_line_circle_intersection() {
+    let c = Circle::from_center_radius(Point::new(1.0, -2.0), 2.0).unwrap();
+    let (P, Q) = Line::from_coeff(0.0, 1.0, 2.0).unwrap().inter(c).unwrap();
+    assert!(P == Point::new(-1.0, -2.0) && Q == Point::new(3.0, -2.0));
+}
+
+#[test]
+fn circle_relations() {
+    let c = Circle::from_center_radius(Point::new(0.0, 0.0), 3.0).unwrap();
+    let at = |x: f64, r: f64| Circle::from_center_radius(Point::new(x, 0.0), r).unwrap();
+    for (d, rel) in [
+        (at(10.0, 2.0), CircleRelation::Separate),
+        (at(5.0, 2.0), CircleRelation::ExternallyTangent),
+        (at(4.0, 2.0), CircleRelation::Intersecting),
+        (at(1.0, 2.0), CircleRelation::InternallyTangent),
+        (at(0.5, 2.0), CircleRelation::Contained),
+        (at(0.0, 2.0), CircleRelation::Concentric),
+        (at(0.0, 3.0), CircleRelation::Concentric),
+        (at(1.0, 4.0), CircleRelation::InternallyTangent),
+        (at(0.5, 5.0), CircleRelation::Contained),
+    ] {
+        assert_eq!(circle_relation(c, d), rel);
+        assert_eq!(circle_relation(d, c), rel);
+    }
+}
+
+#[test]
+fn cross_ratio_and_harmonic_conjugate() {
+    let A = Point::new(1.0, 1.0);
+    let B = Point::new(5.0, 3.0);
+    let on = |t: f64| A + (B - A) * t;
+    // `(0, 1; 2, 3) = (2 / 1) / (3 / 2)`.
+    assert!((cross_ratio(A, B, on(2.0), on(3.0)).unwrap() - 4.0 / 3.0).abs() < EPSILON);
+    let C = on(0.25);
+    let D = harmonic_conjugate(A, B, C).unwrap();
+    assert!((cross_ratio(A, B, C, D).unwrap() + 1.0).abs() < EPSILON);
+    assert!(harmonic_conjugate(A, B, D).unwrap() == C);
+    let C = on(-2.0);
+    assert!(harmonic_conjugate(A, B, harmonic_conjugate(A, B, C).unwrap()).unwrap() == C);
+    assert_eq!(
+        harmonic_conjugate(A, B, on(0.5)).unwrap_err(),
+        CalcException::Infinity
+    );
+    assert_eq!(
+        harmonic_conjugate(A, B, Point::new(0.0, 0.0)).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+    assert_eq!(
+        cross_ratio(A, B, on(2.0), Point::new(0.0, 0.0)).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+    assert_eq!(
+        cross_ratio(A, B, B, on(2.0)).unwrap_err(),
+        CalcException::Infinity
+    );
+    assert_eq!(
+        cross_ratio(A, A, B, on(2.0)).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+}
+
+#[test]
+fn coincident_and_parallel_lines() {
+    let l = Line::from_coeff(1.0, -2.0, 3.0).unwrap();
+    let k = Line::from_coeff(-2.0, 4.0, -6.0).unwrap();
+    assert_eq!(l.inter(l).unwrap_err(), CalcException::CoincidentLines);
+    assert_eq!(l.inter(k).unwrap_err(), CalcException::CoincidentLines);
+    let m = Line::from_coeff(2.0, -4.0, 1.0).unwrap();
+    assert_eq!(l.inter(m).unwrap_err(), CalcException::NoIntersection);
+    let n = Line::from_coeff(1.0, 1.0, 0.0).unwrap();
+    assert_eq!(l.inter(n).unwrap(), Point::new(-1.0, 1.0));
+}
+
+#[test]
+fn checked_common_point() {
+    let c = Circle::from_center_radius(Point::new(0.0, 0.0), 5.0).unwrap();
+    let d = Circle::from_center_radius(Point::new(4.0, 0.0), 3.0).unwrap();
+    let A = Point::new(4.0, 3.0);
+    // `A` is the upper intersection, so this agrees with `inter`.
+    let (P, Q) = c.inter(d).unwrap();
+    let (R, S) = c.inter_common_checked(d, A).unwrap();
+    assert!(P == R && Q == S && S == A);
+    let l = Line::from_2p(Point::new(-5.0, 0.0), A).unwrap();
+    assert_eq!(
+        l.inter_common_checked(c, A).unwrap(),
+        l.inter_common(c, A).unwrap()
+    );
+    // A point on only one of the objects, and a point on neither.
+    assert_eq!(
+        c.inter_common_checked(d, Point::new(0.0, 5.0)).unwrap_err(),
+        CalcException::NoIntersection
+    );
+    assert_eq!(
+        l.inter_common_checked(c, Point::new(1.0, 1.0)).unwrap_err(),
+        CalcException::NoIntersection
+    );
+}
+
+#[test]
+fn batch_intersections() {
+    // A xorshift generator, for reproducible inputs.
+    let mut seed = 0x2545f4914f6cdd1du64;
+    let mut rand = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed >> 11) as f64 / (1u64 << 53) as f64 * 10.0 - 5.0
+    };
+    let c = Circle::from_center_radius(Point::new(0.5, -1.0), 3.0).unwrap();
+    let lines: Vec<Line> = (0..200)
+        .map(|_| Line::from_2p(Point::new(rand(), rand()), Point::new(rand(), rand())).unwrap())
+        .collect();
+    let mut circles: Vec<Circle> = (0..200)
+        .map(|_| {
+            Circle::from_center_radius(Point::new(rand(), rand()), rand().abs() + 0.5).unwrap()
+        })
+        .collect();
+    // Concentric Circles.
+    circles.push(Circle::from_center_radius(c.O, 1.0).unwrap());
+    circles.push(c);
+    let mut hits = 0;
+    for (l, r) in lines.iter().zip(inter_batch(&lines, c)) {
+        match (l.inter(c), r) {
+            (Ok((P, Q)), Ok((R, S))) => {
+                assert!(P == R && Q == S);
+                hits += 1;
+            }
+            (Err(e), Err(f)) => assert_eq!(e, f),
+            (a, b) => panic!("{:?} differs from {:?}", a, b),
+        }
+    }
+    for (d, r) in circles.iter().zip(inter_batch_circles(&circles, c)) {
+        match (d.inter(c), r) {
+            (Ok((P, Q)), Ok((R, S))) => assert!(P == R && Q == S),
+            (Err(e), Err(f)) => assert_eq!(e, f),
+            (a, b) => panic!("{:?} differs from {:?}", a, b),
+        }
+    }
+    assert!(hits > 0 && hits < 200);
+    assert_eq!(
+        Circle::from_center_radius(c.O, 1.0)
+            .unwrap()
+            .inter_common(c, c.O)
+            .unwrap_err(),
+        CalcException::NoIntersection
+    );
+}
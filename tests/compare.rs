@@ -0,0 +1,89 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{basic::*, compare::*, constants::EPSILON},
+    objects::*,
+};
+
+#[test]
+fn default_matches_partial_eq() {
+    let cmp = Comparator::default();
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(3.0, 4.0);
+    let C = Point::new(3.0, 4.0 + 1e-12);
+    assert_eq!(cmp.points_eq(B, C), B == C);
+    let c = Circle::from_center_radius(A, 5.0).unwrap();
+    assert_eq!(cmp.on_circle(C, c), c.is_through(C));
+    let l = Line::from_2p(A, B).unwrap();
+    assert_eq!(cmp.on_line(C, l), l.is_through(C));
+}
+
+#[test]
+fn two_scales() {
+    let fine = Comparator::new(1e-10);
+    let coarse = Comparator::new(1e-3);
+    // A figure at the scale of thousands, with small accumulated errors.
+    let A = Point::new(1000.0, 2000.0);
+    let B = Point::new(4000.0, 6000.0);
+    let C = Point::new(7000.0, 10000.0 + 1e-7);
+    assert!(!fine.collinear(A, B, C));
+    assert!(coarse.collinear(A, B, C));
+    let l = Line::from_2p(A, B).unwrap();
+    let k = Line::from_2p(Point::new(1000.0, 2001.0), C + Point::new(0.0, 1.0)).unwrap();
+    assert!(!fine.is_parallel(l, k));
+    assert!(coarse.is_parallel(l, k));
+    assert!(!fine.on_line(C, l));
+    assert!(coarse.on_line(C, l));
+    let c = Circle::from_center_radius(A, 5000.0).unwrap();
+    let d = Circle::from_center_radius(A + Point::new(1e-5, 0.0), 5000.0 + 1e-5).unwrap();
+    assert!(!fine.circles_eq(c, d));
+    assert!(coarse.circles_eq(c, d));
+    assert!(!fine.points_eq(c.O, d.O));
+    assert!(coarse.points_eq(c.O, d.O));
+    assert!(fine.on_circle(
+        Point::new(5000.0, 2000.0 + 3000.0),
+        Circle::from_center_radius(Point::new(1000.0, 2000.0), 5000.0).unwrap()
+    ));
+    assert!(coarse.lines_eq(l, Line { c: l.c + 1e-4, ..l }));
+    assert!(!fine.lines_eq(l, Line { c: l.c + 1e-4, ..l }));
+}
+
+#[test]
+fn geo_assert_eq_passes() {
+    let A = Point::new(1.0, 2.0);
+    metric_rs::geo_assert_eq!(A, Point::new(1.0, 2.0 + 1e-12));
+    metric_rs::geo_assert_eq!(A, Point::new(1.0, 2.001), 1e-2);
+    let l = Line::from_2p(A, Point::new(3.0, 5.0)).unwrap();
+    metric_rs::geo_assert_eq!(l, l);
+    let c = Circle::from_center_radius(A, 2.0).unwrap();
+    metric_rs::geo_assert_eq!(c, Circle { r: 2.0 + 1e-4, ..c }, 1e-3,);
+}
+
+#[test]
+#[should_panic(expected = "right: ( 1 , 2.001 )\nresidual:")]
+fn geo_assert_eq_fails() {
+    metric_rs::geo_assert_eq!(Point::new(1.0, 2.0), Point::new(1.0, 2.001));
+}
+
+#[test]
+#[should_panic(expected = "assertion `left ≈ right` failed")]
+fn geo_assert_eq_fails_with_eps() {
+    let c = Circle::from_center_radius(Point::new(0.0, 0.0), 1.0).unwrap();
+    metric_rs::geo_assert_eq!(c, Circle { r: 1.1, ..c }, 1e-2);
+}
+
+#[test]
+fn approx_eq_tolerances() {
+    let O = Point::new(1.0, 2.0);
+    let c = Circle::from_center_radius(O, 3.0).unwrap();
+    let d = Circle::from_center_radius(O, 3.0 + 1e-11).unwrap();
+    assert!(c.approx_eq(&d, EPSILON) && c == d);
+    let e = Circle::from_center_radius(O, 3.0 + 1e-8).unwrap();
+    assert!(!c.approx_eq(&e, EPSILON) && c != e);
+    assert!(c.approx_eq(&e, 1e-6));
+    assert!(1.0f64.approx_eq(&(1.0 + 1e-8), 1e-6));
+    assert!(!O.approx_eq(&Point::new(1.0, 2.001), 1e-4));
+    let l = Line::from_coeff(1.0, -1.0, 2.0).unwrap();
+    let k = Line::from_coeff(1.0, -1.0 + 1e-8, 2.0).unwrap();
+    assert!(l != k && l.approx_eq(&k, 1e-6));
+}
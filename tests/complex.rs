@@ -0,0 +1,27 @@
+#![cfg(feature = "complex")]
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{constants::ORIGIN, transform::Rotate},
+    objects::*,
+};
+use num_complex::Complex;
+
+#[test]
+fn complex_round_trip() {
+    let P = Point::new(1.5, -2.0);
+    let z = P.to_complex();
+    assert_eq!(z, Complex::new(1.5, -2.0));
+    assert_eq!(Point::from_complex(z), P);
+    let w: Complex<f64> = P.into();
+    assert_eq!(Point::from(w), P);
+}
+
+#[test]
+fn multiplication_rotates_and_scales() {
+    let P = Point::new(3.0, 1.0);
+    let t = 0.7;
+    let w = Complex::from_polar(2.0, t);
+    let Q = Point::from(P.to_complex() * w);
+    assert_eq!(Q, P.rotate(ORIGIN, t) * 2.0);
+}
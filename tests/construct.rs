@@ -0,0 +1,388 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{
+        basic::*,
+        construct::*,
+        exception::CalcException,
+        point_on::PointOn,
+        transform::{Invert, LineInverted, Reflect},
+    },
+    objects::*,
+};
+
+#[test]
+fn radical_axis_foot_points() {
+    let c = Circle::from_center_radius(Point::new(0.0, 0.0), 3.0).unwrap();
+    let d = Circle::from_center_radius(Point::new(2.0, 7.0), 1.5).unwrap();
+    let l = radical_axis(c, d);
+    let (P, Q) = radical_axis_feet(c, d);
+    assert!(l.is_through(P));
+    assert!(l.is_through(Q));
+    assert!(Line::from_2p(c.O, d.O).unwrap().is_through(P));
+}
+
+#[test]
+fn apollonius_circle() {
+    let A = Point::new(1.0, 1.0);
+    let B = Point::new(4.0, 5.0);
+    let k = 2.5;
+    let c = apollonius(A, B, k).unwrap();
+    let P = (A + B * k) / (1.0 + k);
+    let Q = (A - B * k) / (1.0 - k);
+    assert!(c.is_through(P));
+    assert!(c.is_through(Q));
+    let l = Line::from_2p(A, B).unwrap();
+    let (X, _) = perp(c.O, l).inter(c).unwrap();
+    assert!((X.distance(A) / X.distance(B) - k).abs() < 1e-10);
+    assert_eq!(apollonius(A, B, 1.0).unwrap_err(), CalcException::Infinity);
+    assert_eq!(
+        apollonius(A, B, -1.0).unwrap_err(),
+        CalcException::NonpositiveRadius
+    );
+}
+
+#[test]
+fn common_incircle() {
+    let c = Circle::from_center_radius(Point::new(1.0, 2.0), 3.0).unwrap();
+    let [l1, l2, l3, l4] = [0.3, 1.7, 3.5, 5.0].map(|t| polar_line(c.point_on(t), c).unwrap());
+    assert!(have_common_incircle(l1, l2, l3, l4).unwrap() == c);
+    // A square, with two pairs of parallel sides.
+    let l1 = Line::from_coeff(1.0, 0.0, -1.0).unwrap();
+    let l2 = Line::from_coeff(0.0, 1.0, -1.0).unwrap();
+    let l3 = Line::from_coeff(1.0, 0.0, 1.0).unwrap();
+    let l4 = Line::from_coeff(0.0, 1.0, 1.0).unwrap();
+    let d = have_common_incircle(l1, l2, l3, l4).unwrap();
+    assert!(d == Circle::from_center_radius(Point::new(0.0, 0.0), 1.0).unwrap());
+    let l4 = Line::from_coeff(0.0, 1.0, 2.0).unwrap();
+    assert!(have_common_incircle(l1, l2, l3, l4).is_none());
+}
+
+#[test]
+fn line_circle_parameters() {
+    let c = Circle::from_center_radius(Point::new(0.0, 0.0), 5.0).unwrap();
+    // The chord `y = 3` has endpoints `(4, 3)` and `(-4, 3)`, direction `(-b, a) = (-1, 0)`.
+    let l = Line::from_coeff(0.0, 1.0, -3.0).unwrap();
+    let (s, t) = line_circle_params(l, c, Point::new(1.0, 0.0)).unwrap();
+    assert!((s + 3.0).abs() < 1e-10);
+    assert!((t - 5.0).abs() < 1e-10);
+    let k = Line::from_coeff(0.0, 1.0, -6.0).unwrap();
+    assert_eq!(
+        line_circle_params(k, c, Point::new(0.0, 0.0)).unwrap_err(),
+        CalcException::NoIntersection
+    );
+}
+
+#[test]
+fn lerp_and_point_on_line() {
+    let A = Point::new(1.0, 2.0);
+    let B = Point::new(-3.0, 7.0);
+    assert!(A.lerp(B, 0.5) == midpoint(A, B));
+    assert!(A.lerp(B, 0.0) == A && A.lerp(B, 1.0) == B);
+    let l = Line::from_2p(A, B).unwrap();
+    let P: Vec<Point> = (-3..4).map(|i| l.point_on(i as f64 * 0.75)).collect();
+    assert!(P[3] == projection(Point::new(0.0, 0.0), l));
+    for w in P.windows(2) {
+        assert!(l.is_through(w[0]));
+        assert!((w[0].distance(w[1]) - 0.75).abs() < 1e-10);
+        assert!((w[1] - w[0]).dot(l.direction()) > 0.0);
+    }
+}
+
+#[test]
+fn all_common_tangents() {
+    let c = Circle::from_center_radius(Point::new(0.0, 0.0), 3.0).unwrap();
+    let at = |x: f64, r: f64| Circle::from_center_radius(Point::new(x, 1.0), r).unwrap();
+    for (d, n) in [
+        (at(10.0, 2.0), 4),
+        (at(10.0, 3.0), 4),
+        (at(24f64.sqrt(), 2.0), 3),
+        (at(4.0, 2.0), 2),
+        (at(4.0, 3.0), 2),
+        (at(1.0, 3.0 - 2f64.sqrt()), 1),
+        (at(0.5, 1.0), 0),
+        (Circle::from_center_radius(c.O, 1.0).unwrap(), 0),
+    ] {
+        let T = common_tangents(c, d);
+        assert_eq!(T.len(), n);
+        for &l in &T {
+            assert!((c.O.distance(l) - c.r).abs() < 1e-9);
+            assert!((d.O.distance(l) - d.r).abs() < 1e-9);
+        }
+        for i in 0..T.len() {
+            for j in 0..i {
+                assert!(projection(c.O, T[i]) != projection(c.O, T[j]));
+            }
+        }
+    }
+    // Equal radii give parallel outer tangents.
+    let T = common_tangents(c, at(10.0, 3.0));
+    assert!(is_parallel(T[0], T[1]));
+}
+
+#[test]
+fn pole_of_polar() {
+    let c = Circle::from_center_radius(Point::new(1.0, -2.0), 3.0).unwrap();
+    for P in [
+        Point::new(5.0, 4.0),
+        Point::new(1.5, -1.0),
+        Point::new(4.0, -2.0),
+    ] {
+        assert!(pole(polar_line(P, c).unwrap(), c).unwrap() == P);
+    }
+    let l = Line::from_coeff(2.0, 1.0, 7.0).unwrap();
+    assert!(polar_line(pole(l, c).unwrap(), c).unwrap().normalized() == l.normalized());
+    assert_eq!(
+        pole(Line::from_2p(c.O, Point::new(0.0, 0.0)).unwrap(), c).unwrap_err(),
+        CalcException::Infinity
+    );
+}
+
+#[test]
+fn circles_tangent_to_line_at_point() {
+    let l = Line::from_coeff(3.0, -4.0, 5.0).unwrap();
+    let P = Point::new(1.0, 2.0);
+    let (c, d) = circle_tangent_to_line_at(P, l, 2.5).unwrap();
+    for e in [c, d] {
+        assert!((e.distance(l)).abs() < 1e-10);
+        assert!(e.is_through(P) && (e.r - 2.5).abs() < 1e-10);
+    }
+    assert!(c.O != d.O && midpoint(c.O, d.O) == P);
+    assert_eq!(
+        circle_tangent_to_line_at(Point::new(0.0, 0.0), l, 1.0).unwrap_err(),
+        CalcException::NoIntersection
+    );
+    assert_eq!(
+        circle_tangent_to_line_at(P, l, 0.0).unwrap_err(),
+        CalcException::NonpositiveRadius
+    );
+}
+
+#[test]
+fn circle_through_point_tangent_to_line() {
+    let l = Line::from_coeff(3.0, -4.0, 5.0).unwrap();
+    let T = Point::new(1.0, 2.0);
+    for P in [Point::new(4.0, -1.0), Point::new(-3.0, 6.0)] {
+        let c = circle_through_tangent_to_line(P, T, l).unwrap();
+        assert!(c.is_through(P) && c.is_through(T));
+        assert!(c.distance(l) < 1e-10);
+        assert!(projection(c.O, l) == T);
+    }
+    assert_eq!(
+        circle_through_tangent_to_line(Point::new(4.0, -1.0), Point::new(0.0, 0.0), l).unwrap_err(),
+        CalcException::NoIntersection
+    );
+    assert_eq!(
+        circle_through_tangent_to_line(T, T, l).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+    assert_eq!(
+        circle_through_tangent_to_line(Point::new(5.0, 5.0), T, l).unwrap_err(),
+        CalcException::Infinity
+    );
+}
+
+#[test]
+fn descartes_circles() {
+    let tangent = |c: Circle, d: Circle| {
+        let D = c.O.distance(d.O);
+        (D - (c.r + d.r)).abs() < 1e-9 || (D - (c.r - d.r).abs()).abs() < 1e-9
+    };
+    // Three externally tangent circles with radii 1, 2, 3, the centers form a 3-4-5 triangle.
+    let c1 = Circle::from_center_radius(Point::new(0.0, 0.0), 1.0).unwrap();
+    let c2 = Circle::from_center_radius(Point::new(3.0, 0.0), 2.0).unwrap();
+    let c3 = Circle::from_center_radius(Point::new(0.0, 4.0), 3.0).unwrap();
+    // A configuration with an enclosing circle.
+    let e1 = Circle::from_center_radius(Point::new(0.0, 0.0), 2.0).unwrap();
+    let e2 = Circle::from_center_radius(Point::new(-1.0, 0.0), 1.0).unwrap();
+    let e3 = Circle::from_center_radius(Point::new(1.0, 0.0), 1.0).unwrap();
+    for (a, b, c) in [(c1, c2, c3), (c3, c1, c2), (e1, e2, e3), (e2, e3, e1)] {
+        assert!(tangent(a, b) && tangent(b, c) && tangent(c, a));
+        let (d, e) = descartes_fourth(a, b, c).unwrap();
+        for f in [d, e] {
+            assert!(tangent(f, a) && tangent(f, b) && tangent(f, c));
+        }
+        assert!(d.r <= e.r);
+    }
+    // Radii 1, 2, 3 give curvatures 23/6 and -1/6 (an enclosing circle of radius 6).
+    let (d, e) = descartes_fourth(c1, c2, c3).unwrap();
+    assert!((d.r - 6.0 / 23.0).abs() < 1e-9 && (e.r - 6.0).abs() < 1e-9);
+    let (d, e) = descartes_fourth(e1, e2, e3).unwrap();
+    assert!((d.r - 2.0 / 3.0).abs() < 1e-9 && (e.r - 2.0 / 3.0).abs() < 1e-9);
+    assert!(d.O != e.O);
+    // Curvatures 1, 1, 4 give a zero curvature solution, the line `y = 1`.
+    let f1 = Circle::from_center_radius(Point::new(-1.0, 0.0), 1.0).unwrap();
+    let f2 = Circle::from_center_radius(Point::new(1.0, 0.0), 1.0).unwrap();
+    let f3 = Circle::from_center_radius(Point::new(0.0, 0.75), 0.25).unwrap();
+    assert!(tangent(f1, f3) && tangent(f2, f3));
+    assert_eq!(
+        descartes_fourth(f1, f2, f3).unwrap_err(),
+        CalcException::Infinity
+    );
+    let far = Circle::from_center_radius(Point::new(10.0, 0.0), 1.0).unwrap();
+    assert_eq!(
+        descartes_fourth(c1, c2, far).unwrap_err(),
+        CalcException::NoIntersection
+    );
+}
+
+#[test]
+fn steiner_chains() {
+    let O = Point::new(1.0, 1.0);
+    // For `n = 6` the radii must be in ratio `3`.
+    let inner = Circle::from_center_radius(O, 1.0).unwrap();
+    let outer = Circle::from_center_radius(O, 3.0).unwrap();
+    assert!(steiner_chain_closes(inner, outer, 6).unwrap());
+    assert!(!steiner_chain_closes(inner, outer, 5).unwrap());
+    assert!(!steiner_chain_closes(inner, outer, 0).unwrap());
+    // A ratio near `3` does not close.
+    let wider = Circle::from_center_radius(O, 3.001).unwrap();
+    assert!(!steiner_chain_closes(inner, wider, 6).unwrap());
+    // For `n = 4` the ratio is `(1 + s) / (1 - s)` with `s = sin(pi / 4)`.
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    let outer4 = Circle::from_center_radius(O, (1.0 + s) / (1.0 - s)).unwrap();
+    assert!(steiner_chain_closes(inner, outer4, 4).unwrap());
+    assert!(!steiner_chain_closes(inner, outer4, 6).unwrap());
+    // Closing is invariant under inversion.
+    let P = Point::new(10.0, 0.0);
+    let (LineInverted::Circle(a), LineInverted::Circle(b)) = (
+        inner.invert_in(P, 4.0).unwrap(),
+        outer.invert_in(P, 4.0).unwrap(),
+    ) else {
+        panic!("inversion center is not on the circles");
+    };
+    let (a, b) = if a.r < b.r { (a, b) } else { (b, a) };
+    assert!(a.O != b.O);
+    assert!((inversive_distance(a, b) - inversive_distance(inner, outer)).abs() < 1e-9);
+    assert!(steiner_chain_closes(a, b, 6).unwrap());
+    assert_eq!(
+        steiner_chain_closes(outer, inner, 6).unwrap_err(),
+        CalcException::NoIntersection
+    );
+    let far = Circle::from_center_radius(Point::new(10.0, 1.0), 2.0).unwrap();
+    assert_eq!(
+        steiner_chain_closes(far, outer, 6).unwrap_err(),
+        CalcException::NoIntersection
+    );
+}
+
+#[test]
+fn regular_polygons() {
+    let O = Point::new(1.0, -2.0);
+    let hexagon = regular_polygon(O, 3.0, 6, 0.3).unwrap();
+    assert_eq!(hexagon.0.len(), 6);
+    for i in 0..6 {
+        let (P, Q) = (hexagon.0[i], hexagon.0[(i + 1) % 6]);
+        assert!((P.distance(Q) - 3.0).abs() < 1e-10);
+    }
+    assert_eq!(hexagon.centroid(), O);
+    assert!(hexagon.signed_area() > 0.0);
+    assert_eq!(
+        regular_polygon(O, 3.0, 2, 0.0).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+    assert_eq!(
+        regular_polygon(O, 0.0, 5, 0.0).unwrap_err(),
+        CalcException::NonpositiveRadius
+    );
+}
+
+#[test]
+fn star_polygons() {
+    let O = Point::new(0.5, 0.5);
+    let star = star_polygon(O, 2.0, 5, 2).unwrap();
+    let edges: Vec<Segment> = (0..5)
+        .map(|i| Segment::from_2p(star.0[i], star.0[(i + 1) % 5]).unwrap())
+        .collect();
+    // Each edge crosses the two edges not adjacent to it.
+    let mut crossings = 0;
+    for i in 0..5 {
+        for j in i + 2..5 {
+            if (i, j) != (0, 4) && edges[i].inter(edges[j]).is_ok() {
+                crossings += 1;
+            }
+        }
+    }
+    assert_eq!(crossings, 5);
+    assert!(edges[0].inter(edges[1]).unwrap() == star.0[1]);
+    for (n, k) in [(5, 1), (6, 2), (6, 3), (7, 4), (4, 2)] {
+        assert_eq!(
+            star_polygon(O, 2.0, n, k).unwrap_err(),
+            CalcException::ZeroCoefficient
+        );
+    }
+    assert_eq!(star_polygon(O, 2.0, 7, 3).unwrap().0.len(), 7);
+    assert_eq!(
+        star_polygon(O, -1.0, 5, 2).unwrap_err(),
+        CalcException::NonpositiveRadius
+    );
+}
+
+#[test]
+fn overlapping_points_are_errors() {
+    let A = Point::new(1.0, 2.0);
+    let B = Point::new(3.0, -1.0);
+    assert_eq!(
+        perp_bisect(A, A).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+    assert_eq!(
+        angle_bisect_3p(A, A, B).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+    assert_eq!(
+        angle_bisect_3p(A, B, B).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+    let l = perp_bisect(A, B).unwrap();
+    assert!(l.is_through(midpoint(A, B)));
+}
+
+#[test]
+fn tangents_through_a_point() {
+    let c = Circle::from_center_radius(Point::new(1.0, 1.0), 5.0).unwrap();
+    // Inside, including the center.
+    for A in [Point::new(2.0, 3.0), c.O] {
+        assert!(power(A, c) < 0.0);
+        assert_eq!(tangent(A, c).unwrap_err(), CalcException::NoIntersection);
+    }
+    // On the circle, a single tangent.
+    let T = Point::new(4.0, 5.0);
+    assert!(power(T, c).abs() < 1e-10);
+    let (l, k) = tangent(T, c).unwrap();
+    assert!(l == k && l.is_through(T));
+    assert!((c.O.distance(l) - c.r).abs() < 1e-10);
+    // Outside, two tangents of length `sqrt(power)`.
+    let A = Point::new(14.0, 1.0);
+    assert!((power(A, c) - 144.0).abs() < 1e-10);
+    let (l, k) = tangent(A, c).unwrap();
+    assert!(l != k);
+    for m in [l, k] {
+        assert!(m.is_through(A));
+        assert!((c.O.distance(m) - c.r).abs() < 1e-10);
+        assert!((A.distance(projection(c.O, m)) - 12.0).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn tangent_order() {
+    let c = Circle::from_center_radius(Point::new(1.0, -2.0), 2.0).unwrap();
+    for A in [
+        Point::new(7.0, -2.0),
+        Point::new(-5.0, -2.0),
+        Point::new(1.0, 6.0),
+        Point::new(1.0, -9.0),
+        Point::new(-3.0, 4.0),
+    ] {
+        let (l, k) = tangent(A, c).unwrap();
+        let (P, Q) = (projection(c.O, l), projection(c.O, k));
+        // The first tangent point is counterclockwise from `AO` as seen from `A`.
+        assert!((c.O - A).cross(P - A) > 0.0);
+        assert!((c.O - A).cross(Q - A) < 0.0);
+        // The tangents are symmetric about `AO`.
+        let AO = Line::from_2p(A, c.O).unwrap();
+        assert!(P.reflect_in(AO) == Q);
+        // Repeated calls give the same order.
+        assert_eq!(tangent(A, c).unwrap(), (l, k));
+    }
+}
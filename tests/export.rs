@@ -0,0 +1,51 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    export::{svg::*, tikz::*},
+    objects::*,
+};
+
+#[test]
+fn svg_document() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(3.0, 1.0);
+    let C = Point::new(-1.0, 2.0);
+    let lines = [
+        Line::from_2p(A, B).unwrap(),
+        Line::from_2p(B, C).unwrap(),
+        // Outside the viewbox.
+        Line::from_coeff(1.0, 1.0, -100.0).unwrap(),
+        // Vertical.
+        Line::from_coeff(1.0, 0.0, -2.0).unwrap(),
+    ];
+    let circles = [Circle::from_center_radius(A, 2.0).unwrap()];
+    let s = to_svg(&[A, B, C], &lines, &circles, (-5.0, -5.0, 10.0, 10.0));
+    assert!(s.starts_with("<svg"));
+    assert!(s.trim_end().ends_with("</svg>"));
+    assert_eq!(s.matches("<circle").count(), 4);
+    assert_eq!(s.matches("<line").count(), 3);
+    assert!(s.contains(r#"<line x1="2" y1="-5" x2="2" y2="5""#));
+}
+
+#[test]
+fn tikz_picture() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(3.0, 1.0);
+    let C = Point::new(-1.0, 2.0);
+    let lines = [
+        Line::from_2p(A, B).unwrap(),
+        Line::from_coeff(1.0, 1.0, -100.0).unwrap(),
+    ];
+    let circles = [
+        Circle::from_center_radius(A, 2.0).unwrap(),
+        Circle::from_3p(A, B, C).unwrap(),
+    ];
+    let s = to_tikz(&[(A, "$A$"), (B, "$B$"), (C, "$C$")], &lines, &circles);
+    assert!(s.starts_with("\\begin{tikzpicture}"));
+    assert!(s.trim_end().ends_with("\\end{tikzpicture}"));
+    let draws: Vec<&str> = s.lines().filter(|l| l.starts_with("\\draw")).collect();
+    assert_eq!(draws.iter().filter(|l| l.contains("circle")).count(), 2);
+    assert_eq!(draws.iter().filter(|l| l.contains("--")).count(), 1);
+    assert_eq!(s.matches("\\node").count(), 3);
+    assert!(s.contains("{$B$};"));
+}
@@ -0,0 +1,25 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{calc::construct::midpoint, figure::*, objects::*};
+
+#[test]
+fn push_and_undo() {
+    let mut fig = Figure::new();
+    assert!(fig.is_empty());
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(4.0, 2.0);
+    fig.push_step(A);
+    fig.push_step(B);
+    let l = Line::from_2p(A, B).unwrap();
+    assert_eq!(fig.push_step(l), 2);
+    let M = fig.push_step(midpoint(A, B));
+    assert_eq!(fig.get(M), Some(Primitive::Point(Point::new(2.0, 1.0))));
+    assert_eq!(fig.len(), 4);
+    assert_eq!(fig.undo(), Some(Primitive::Point(Point::new(2.0, 1.0))));
+    assert_eq!(fig.undo(), Some(Primitive::Line(l)));
+    assert_eq!(fig.steps(), &[Primitive::Point(A), Primitive::Point(B)]);
+    fig.undo();
+    fig.undo();
+    assert_eq!(fig.undo(), None);
+    assert!(fig.is_empty());
+}
@@ -0,0 +1,69 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{basic::*, exception::CalcException, fit::*},
+    objects::*,
+};
+
+#[test]
+fn fit_noisy_circle() {
+    let O = Point::new(3.0, -2.0);
+    let r = 4.0;
+    // Deterministic noise of magnitude up to 1e-3.
+    let P: Vec<Point> = (0..50)
+        .map(|i| {
+            let t = i as f64 * 0.37;
+            let noise = ((i * 7919) % 13) as f64 / 13.0 * 2e-3 - 1e-3;
+            O + Point::new(t.cos(), t.sin()) * (r + noise)
+        })
+        .collect();
+    let c = fit_circle(&P).unwrap();
+    assert!((c.O - O).norm() < 1e-2);
+    assert!((c.r - r).abs() < 1e-2);
+    // Exact samples are recovered exactly.
+    let c = fit_circle(
+        &P[..3]
+            .iter()
+            .map(|&Q| O + (Q - O) / Q.distance(O) * r)
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    assert!(c == Circle { O, r });
+}
+
+#[test]
+fn fit_circle_degenerate() {
+    let A = Point::new(1.0, 2.0);
+    assert_eq!(
+        fit_circle(&[A, A * 2.0]).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+    assert_eq!(
+        fit_circle(&[A, A * 2.0, A * -3.0, A * 0.5]).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+}
+
+#[test]
+fn fit_lines() {
+    let P: Vec<Point> = (0..10).map(|i| Point::new(5.0, i as f64 - 3.0)).collect();
+    let l = fit_line(&P).unwrap();
+    assert!((l.a * l.a + l.b * l.b - 1.0).abs() < 1e-10);
+    assert!(l.b.abs() < 1e-10);
+    assert!(P.iter().all(|&Q| l.is_through(Q)));
+    // Points scattered symmetrically about `y = 2x + 1`.
+    let P: Vec<Point> = (0..10)
+        .map(|i| {
+            let x = i as f64;
+            let e = if i % 2 == 0 { 1e-3 } else { -1e-3 };
+            Point::new(x, 2.0 * x + 1.0) + Point::new(-2.0, 1.0) * e
+        })
+        .collect();
+    let l = fit_line(&P).unwrap();
+    assert!(Point::new(0.0, 1.0).distance(l) < 1e-3);
+    assert!(Point::new(9.0, 19.0).distance(l) < 1e-3);
+    assert_eq!(
+        fit_line(&[P[0], P[0]]).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+}
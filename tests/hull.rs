@@ -0,0 +1,85 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{basic::*, constants::EPSILON, hull::*},
+    objects::*,
+};
+
+#[test]
+fn square_with_interior_points() {
+    let mut P = vec![
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 0.0),
+        Point::new(0.5, 0.5),
+        Point::new(0.0, 1.0),
+        Point::new(0.5, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(0.2, 0.7),
+        Point::new(1.0, 1.0 + 1e-12),
+    ];
+    let H = convex_hull(&P);
+    let expected = [
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 1.0),
+    ];
+    assert_eq!(H.len(), 4);
+    assert!(H.iter().zip(expected).all(|(&A, B)| A == B));
+    assert!(Polygon(H).signed_area() > 0.0);
+    P.reverse();
+    assert_eq!(convex_hull(&P).len(), 4);
+}
+
+#[test]
+fn degenerate_hulls() {
+    assert!(convex_hull(&[]).is_empty());
+    let A = Point::new(1.0, 2.0);
+    let H = convex_hull(&[A, A, A + Point::new(0.0, 1e-12)]);
+    assert_eq!(H.len(), 1);
+    let P: Vec<Point> = (0..5).map(|i| A * i as f64).rev().collect();
+    let H = convex_hull(&P);
+    assert_eq!(H.len(), 2);
+    assert!(H[0] == Point::new(0.0, 0.0) && H[1] == A * 4.0);
+    // Duplicates that do not sort next to each other.
+    let P = [
+        Point::new(3.0, 0.0),
+        Point::new(3.0, 3.0),
+        Point::new(3.0, 2.0),
+        Point::new(3.0 + 1e-11, -2.5e-12),
+    ];
+    let H = convex_hull(&P);
+    assert_eq!(H.len(), 2);
+    assert!(H[0] == P[0] && H[1] == P[1]);
+}
+
+#[test]
+fn enclosing_circle() {
+    assert!(min_enclosing_circle(&[]).is_none());
+    let A = Point::new(1.0, 2.0);
+    let c = min_enclosing_circle(&[A]).unwrap();
+    assert!(c.O == A && c.r == 0.0);
+    let B = Point::new(5.0, 2.0);
+    let c = min_enclosing_circle(&[A, B, Point::new(3.0, 2.5)]).unwrap();
+    assert!(c.O == Point::new(3.0, 2.0) && (c.r - 2.0).abs() < 1e-10);
+    // A cloud of points in a deterministic spiral.
+    let P: Vec<Point> = (0..200)
+        .map(|i| {
+            let t = i as f64 * 0.7;
+            Point::new(t.cos(), t.sin()) * (1.0 + (i % 17) as f64 / 5.0) + A
+        })
+        .collect();
+    let c = min_enclosing_circle(&P).unwrap();
+    for &Q in &P {
+        assert!(c.O.distance(Q) <= c.r + EPSILON);
+    }
+    // The circle is determined by at least two points on its boundary.
+    let on = P
+        .iter()
+        .filter(|&&Q| (c.O.distance(Q) - c.r).abs() < 1e-9)
+        .count();
+    assert!(on >= 2);
+    let collinear: Vec<Point> = (0..10).map(|i| A * i as f64).collect();
+    let c = min_enclosing_circle(&collinear).unwrap();
+    assert!(c.O == A * 4.5 && (c.r - A.norm() * 4.5).abs() < 1e-10);
+}
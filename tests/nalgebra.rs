@@ -0,0 +1,55 @@
+#![cfg(feature = "nalgebra")]
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{exception::CalcException, transform::Affine},
+    objects::*,
+};
+use nalgebra::{Affine2, Matrix3, Point2};
+
+fn sample() -> Affine {
+    Affine::rotation(Point::new(1.0, -1.0), 0.6)
+        .then(Affine::scaling(Point::new(0.5, 2.0), 1.5))
+        .then(Affine::translation(Point::new(-3.0, 0.25)))
+}
+
+#[test]
+fn point_round_trip() {
+    let P = Point::new(1.5, -2.0);
+    let Q: Point2<f64> = P.into();
+    assert_eq!(Q, Point2::new(1.5, -2.0));
+    assert_eq!(Point::from(Q), P);
+}
+
+#[test]
+fn affine_matches_nalgebra() {
+    let f = sample();
+    let M: Matrix3<f64> = f.into();
+    let g: Affine2<f64> = f.into();
+    for P in [
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 3.0),
+        Point::new(-1.5, 0.5),
+    ] {
+        let Q = f.apply(P);
+        assert_eq!(Point::from(g * Point2::from(P)), Q);
+        let v = M * Point2::from(P).to_homogeneous();
+        assert_eq!(Point::new(v.x, v.y), Q);
+    }
+    let h = Affine::from(g);
+    assert_eq!(h.apply(Point::new(2.0, 3.0)), f.apply(Point::new(2.0, 3.0)));
+}
+
+#[test]
+fn affine_from_matrix() {
+    let f = sample();
+    let M: Matrix3<f64> = f.into();
+    let g = Affine::try_from(M).unwrap();
+    assert_eq!(g.m, f.m);
+    assert_eq!(g.t, f.t);
+    let mut N = M;
+    N[(2, 0)] = 0.5;
+    assert_eq!(Affine::try_from(N).unwrap_err(), CalcException::NotAffine);
+    N = M * 2.0;
+    assert_eq!(Affine::try_from(N).unwrap_err(), CalcException::NotAffine);
+}
@@ -0,0 +1,62 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{basic::*, construct::projection, point_set::*},
+    objects::*,
+};
+
+/// A deterministic pseudo-random point set.
+fn sample(n: usize, seed: u64) -> Vec<Point> {
+    let mut s = seed;
+    let mut next = || {
+        s = s
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (s >> 11) as f64 / (1u64 << 53) as f64 * 100.0
+    };
+    (0..n).map(|_| Point::new(next(), next())).collect()
+}
+
+#[test]
+fn closest_pair_matches_brute_force() {
+    for (n, seed) in [(2, 1), (3, 2), (10, 3), (100, 4), (1000, 5)] {
+        let points = sample(n, seed);
+        let (i, j, d) = closest_pair(&points).unwrap();
+        assert!(i < j);
+        assert_eq!(d, points[i].distance(points[j]));
+        let mut best = f64::INFINITY;
+        for a in 0..n {
+            for b in a + 1..n {
+                best = best.min(points[a].distance(points[b]));
+            }
+        }
+        assert_eq!(d, best);
+    }
+}
+
+#[test]
+fn closest_pair_edge_cases() {
+    assert_eq!(closest_pair(&[]), None);
+    assert_eq!(closest_pair(&[Point::new(1.0, 2.0)]), None);
+    let mut points = sample(50, 7);
+    points.push(points[13]);
+    assert_eq!(closest_pair(&points), Some((13, 50, 0.0)));
+}
+
+#[test]
+fn sort_points_along_line() {
+    let A = Point::new(0.0, 1.0);
+    let l = Line::from_slope_and_point(1.0, -2.0, A);
+    let d = Point::new(2.0, 1.0);
+    let ts = [3.0, -1.0, 0.5, 10.0, -4.0, 0.0];
+    let mut points: Vec<Point> = ts.iter().map(|&t| A + d * t).collect();
+    // A point off the line, projecting to `A + d * 2`.
+    points.push(A + d * 2.0 + Point::new(1.0, -2.0));
+    sort_along_line(&mut points, l);
+    let mut expected: Vec<f64> = ts.to_vec();
+    expected.push(2.0);
+    expected.sort_by(f64::total_cmp);
+    for (P, t) in points.iter().zip(expected) {
+        assert!(projection(*P, l) == A + d * t);
+    }
+}
@@ -0,0 +1,105 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{calc::construct::center, objects::*};
+
+fn unit_square() -> Polygon {
+    Polygon(vec![
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 1.0),
+    ])
+}
+
+/// An L-shape made of three unit squares, missing the upper right one.
+fn l_shape() -> Polygon {
+    Polygon(vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 1.0),
+        Point::new(1.0, 1.0),
+        Point::new(1.0, 2.0),
+        Point::new(0.0, 2.0),
+    ])
+}
+
+#[test]
+fn square() {
+    let p = unit_square();
+    assert!((p.signed_area() - 1.0).abs() < 1e-10);
+    assert!(p.centroid() == Point::new(0.5, 0.5));
+    assert!(p.is_convex());
+    let mut q = p.0.clone();
+    q.reverse();
+    let q = Polygon(q);
+    assert!((q.signed_area() + 1.0).abs() < 1e-10);
+    assert!((q.area() - 1.0).abs() < 1e-10);
+    assert!(q.is_convex());
+    // A collinear extra vertex changes neither convexity nor centroid.
+    let mut r = p.0.clone();
+    r.insert(1, Point::new(0.5, 0.0));
+    let r = Polygon(r);
+    assert!(r.is_convex());
+    assert!(r.centroid() == Point::new(0.5, 0.5));
+    assert!(center(&r.0) != r.centroid());
+}
+
+#[test]
+fn l_shape_is_not_convex() {
+    let p = l_shape();
+    assert!((p.area() - 3.0).abs() < 1e-10);
+    assert!(p.centroid() == Point::new(5.0 / 6.0, 5.0 / 6.0));
+    assert!(!p.is_convex());
+    let star = Polygon(
+        (0..5)
+            .map(|i| {
+                let t = i as f64 * 4.0 * std::f64::consts::PI / 5.0;
+                Point::new(t.cos(), t.sin())
+            })
+            .collect(),
+    );
+    assert!(!star.is_convex());
+}
+
+#[test]
+fn contains_concave() {
+    let p = l_shape();
+    assert!(p.contains(Point::new(0.5, 1.5)));
+    assert!(p.contains(Point::new(1.5, 0.5)));
+    // Inside the convex hull but outside the L-shape.
+    assert!(!p.contains(Point::new(1.5, 1.5)));
+    assert!(!p.contains(Point::new(-0.5, 0.5)));
+    // Boundary points, including the reflex vertex, count as inside.
+    assert!(p.contains(Point::new(1.0, 1.5)));
+    assert!(p.contains(Point::new(1.0, 1.0)));
+    assert!(p.contains(Point::new(0.0, 0.0)));
+    // A ray through a vertex is not double counted.
+    assert!(!p.contains(Point::new(3.0, 1.0)));
+    assert!(p.contains(Point::new(0.5, 1.0)));
+    // Repeated vertices and the reversed orientation give the same answers.
+    let mut q = p.0.clone();
+    q.insert(2, q[2]);
+    q.push(q[0]);
+    q.reverse();
+    let q = Polygon(q);
+    assert!(q.contains(Point::new(0.5, 1.5)));
+    assert!(!q.contains(Point::new(1.5, 1.5)));
+    assert!(!Polygon(vec![]).contains(Point::new(0.0, 0.0)));
+}
+
+#[test]
+fn signed_distances() {
+    let square = unit_square();
+    assert!((square.signed_distance(Point::new(0.5, 0.5)) + 0.5).abs() < 1e-10);
+    assert!((square.signed_distance(Point::new(4.0, 5.0)) - 5.0).abs() < 1e-10);
+    assert!((square.signed_distance(Point::new(0.5, -2.0)) - 2.0).abs() < 1e-10);
+    assert!(square.signed_distance(Point::new(1.0, 0.5)).abs() < 1e-10);
+    // The notch of the L-shape is outside.
+    let l = l_shape();
+    assert!((l.signed_distance(Point::new(1.5, 1.5)) - 0.5).abs() < 1e-10);
+    assert!((l.distance_to_boundary(Point::new(0.5, 0.5)) - 0.5).abs() < 1e-10);
+    // Degenerate Polygons.
+    let P = Point::new(3.0, 4.0);
+    assert_eq!(Polygon(vec![]).distance_to_boundary(P), f64::INFINITY);
+    assert!((Polygon(vec![Point::new(0.0, 0.0)]).signed_distance(P) - 5.0).abs() < 1e-10);
+}
@@ -0,0 +1,63 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{
+        basic::Intersect, compare::ApproxEq, construct::midpoint, exception::CalcException,
+        scalar::Scalar,
+    },
+    objects::*,
+};
+
+macro_rules! core_constructions {
+    ($name:ident, $t:ty) => {
+        #[test]
+        fn $name() {
+            let p = |x: $t, y: $t| -> Point<$t> { (x, y).into() };
+            let (A, B, C, D) = (p(0.0, 0.0), p(4.0, 0.0), p(0.0, 3.0), p(4.0, 3.0));
+            // Arithmetic and products.
+            assert_eq!((D - A) * 2.0 / 2.0, D);
+            assert_eq!(-D, p(-4.0, -3.0));
+            assert!((D.norm() - 5.0).abs() < <$t as Scalar>::EPSILON);
+            assert_eq!(B.dot(C), 0.0);
+            assert_eq!(B.cross(C), 12.0);
+            // Lines and their intersections.
+            let l = Line::from_2p(A, D).unwrap();
+            let k = Line::from_2p(B, C).unwrap();
+            let M = l.inter(k).unwrap();
+            assert_eq!(M, midpoint(A, D));
+            assert!(M.approx_eq(&p(2.0, 1.5), <$t as Scalar>::EPSILON));
+            let m = Line::from_2p(C, D).unwrap();
+            let n = Line::from_slope_and_point(m.a, m.b, A);
+            assert_eq!(m.inter(n).unwrap_err(), CalcException::NoIntersection);
+            assert_eq!(
+                m.inter(Line::from_2p(D, C).unwrap()).unwrap_err(),
+                CalcException::CoincidentLines
+            );
+            assert_eq!(
+                Line::from_2p(A, A).unwrap_err(),
+                CalcException::OverlappingPoint
+            );
+            // Circles.
+            let c = Circle::from_center_point(A, D).unwrap();
+            assert_eq!(c, Circle::from_center_radius(A, 5.0).unwrap());
+            assert_eq!(
+                Circle::from_center_radius(A, 0.0).unwrap_err(),
+                CalcException::NonpositiveRadius
+            );
+            assert_eq!(format!("{}", M), "( 2 , 1.5 )");
+        }
+    };
+}
+
+core_constructions!(core_f32, f32);
+core_constructions!(core_f64, f64);
+
+#[test]
+fn f32_is_smaller() {
+    assert_eq!(std::mem::size_of::<Point<f32>>(), 8);
+    assert_eq!(std::mem::size_of::<Circle<f32>>(), 12);
+    assert_eq!(
+        std::mem::size_of::<Point>(),
+        std::mem::size_of::<Point<f64>>()
+    );
+}
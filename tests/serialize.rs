@@ -0,0 +1,26 @@
+#![cfg(feature = "serialize")]
+#![allow(non_snake_case)]
+
+use metric_rs::objects::*;
+
+#[test]
+fn circle_round_trip() {
+    let c = Circle::from_center_radius(Point::new(1.5, -2.0), 3.25).unwrap();
+    let s = serde_json::to_string(&c).unwrap();
+    assert_eq!(s, r#"{"O":{"x":1.5,"y":-2.0},"r":3.25}"#);
+    let d: Circle = serde_json::from_str(&s).unwrap();
+    assert!(c == d);
+    // Invariants are not enforced.
+    let e: Circle = serde_json::from_str(r#"{"O":{"x":0.0,"y":0.0},"r":-1.0}"#).unwrap();
+    assert_eq!(e.r, -1.0);
+}
+
+#[test]
+fn point_and_line_round_trip() {
+    let P = Point::new(0.1, 0.2);
+    let Q: Point = serde_json::from_str(&serde_json::to_string(&P).unwrap()).unwrap();
+    assert!(P == Q);
+    let l = Line::from_2p(P, Point::new(3.0, 4.0)).unwrap();
+    let k: Line = serde_json::from_str(&serde_json::to_string(&l).unwrap()).unwrap();
+    assert!(l == k);
+}
@@ -0,0 +1,419 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{basic::*, exception::CalcException, point_on::PointOn, transform::*},
+    objects::*,
+};
+
+#[test]
+fn segment_transforms() {
+    let s = Segment::from_2p(Point::new(1.0, 2.0), Point::new(4.0, 6.0)).unwrap();
+    let O = Point::new(-1.0, 0.5);
+    let l = Line::from_coeff(2.0, -3.0, 1.0).unwrap();
+    let len = s.A.distance(s.B);
+    let t = s.reflect_in(l);
+    assert!((t.A.distance(t.B) - len).abs() < 1e-10);
+    assert!(t.A == s.A.reflect_in(l));
+    let t = s.reflect_in(O);
+    assert!((t.A.distance(t.B) - len).abs() < 1e-10);
+    let t = s.rotate(O, 1.0);
+    assert!((t.A.distance(t.B) - len).abs() < 1e-10);
+    let t = s.scale(O, 2.0);
+    assert!((t.A.distance(t.B) - 2.0 * len).abs() < 1e-10);
+    assert!(t.B == s.B.scale(O, 2.0));
+}
+
+#[test]
+fn reflect_in_segment() {
+    let s = Segment::from_2p(Point::new(0.0, 0.0), Point::new(4.0, 0.0)).unwrap();
+    let P = Point::new(1.0, 2.0);
+    assert!(P.reflect_in(s) == Point::new(1.0, -2.0));
+    assert!(reflect_in_segment_clamped(P, s) == Point::new(1.0, -2.0));
+    let Q = Point::new(6.0, 1.0);
+    assert!(Q.reflect_in(s) == Point::new(6.0, -1.0));
+    assert!(reflect_in_segment_clamped(Q, s) == Point::new(2.0, -1.0));
+    let R = Point::new(-1.0, -3.0);
+    assert!(reflect_in_segment_clamped(R, s) == Point::new(1.0, 3.0));
+}
+
+#[test]
+fn affine_composition() {
+    let O = Point::new(1.0, -2.0);
+    let P = Point::new(3.0, 4.0);
+    let l = Line::from_coeff(2.0, -3.0, 1.0).unwrap();
+    // The constructors agree with the transform traits.
+    assert!(Affine::rotation(O, 0.7).apply(P) == P.rotate(O, 0.7));
+    assert!(Affine::scaling(O, -1.5).apply(P) == P.scale(O, -1.5));
+    assert!(Affine::reflection(l).apply(P) == P.reflect_in(l));
+    assert!(Affine::translation(O).apply(P) == P + O);
+    // Composition order matters.
+    let r = Affine::rotation(O, 0.7);
+    let t = Affine::translation(Point::new(5.0, 0.0));
+    assert!(r.then(t).apply(P) == P.rotate(O, 0.7) + Point::new(5.0, 0.0));
+    assert!(t.then(r).apply(P) == (P + Point::new(5.0, 0.0)).rotate(O, 0.7));
+    assert!(r.then(t).apply(P) != t.then(r).apply(P));
+    // The inverse undoes the transformation on either side.
+    let f = r
+        .then(Affine::scaling(P, 2.0))
+        .then(Affine::reflection(l))
+        .then(t);
+    let g = f.inverse().unwrap();
+    for Q in [P, O, Point::new(-7.0, 0.5)] {
+        assert!(g.then(f).apply(Q) == Q);
+        assert!(f.then(g).apply(Q) == Q);
+    }
+    let i = g.then(f);
+    let e = Affine::identity();
+    for k in 0..2 {
+        for j in 0..2 {
+            assert!((i.m[k][j] - e.m[k][j]).abs() < 1e-10);
+        }
+    }
+    assert!(Affine::scaling(O, 0.0).inverse().is_err());
+}
+
+#[test]
+fn affine_lines_and_circles() {
+    let O = Point::new(1.0, -2.0);
+    let c = Circle::from_center_radius(Point::new(3.0, 4.0), 2.5).unwrap();
+    let r = Affine::rotation(O, 0.7);
+    let d = r.apply_circle(c).unwrap();
+    assert!(d == c.rotate(O, 0.7));
+    assert!((d.r - c.r).abs() < 1e-10 && d.O == c.O.rotate(O, 0.7));
+    let l = Line::from_coeff(2.0, -3.0, 1.0).unwrap();
+    let (P, Q) = (Point::new(1.0, 1.0), Point::new(-2.0, -1.0));
+    assert!(l.is_through(P) && l.is_through(Q));
+    let k = Line::from_coeff(1.0, 1.0, -4.0).unwrap();
+    let f = r
+        .then(Affine::scaling(P, -2.0))
+        .then(Affine::reflection(k))
+        .then(Affine::translation(O));
+    for g in [r, Affine::reflection(k), f] {
+        let m = g.apply_line(l);
+        assert!(m.is_through(g.apply(P)) && m.is_through(g.apply(Q)));
+        assert!(g
+            .apply_circle(c)
+            .unwrap()
+            .is_through(g.apply(c.O + Point::new(2.5, 0.0))));
+    }
+    // The traits are special cases of `Affine`.
+    assert!(r.apply_line(l) == l.rotate(O, 0.7));
+    assert!(l.rotate(O, 0.7).is_through(P.rotate(O, 0.7)));
+    let shear = Affine {
+        m: [[1.0, 0.5], [0.0, 1.0]],
+        t: O,
+    };
+    let m = shear.apply_line(l);
+    assert!(m.is_through(shear.apply(P)) && m.is_through(shear.apply(Q)));
+    assert_eq!(
+        shear.apply_circle(c).unwrap_err(),
+        CalcException::NotSimilarity
+    );
+}
+
+#[test]
+fn translations() {
+    let by = Point::new(-3.0, 1.5);
+    let l = Line::from_coeff(2.0, -3.0, 1.0).unwrap();
+    let P = Point::new(1.0, 1.0);
+    let k = l.translate(by);
+    assert!(is_parallel(l, k));
+    assert!(k.is_through(P.translate(by)));
+    assert!(!k.is_through(P));
+    assert!(k == Affine::translation(by).apply_line(l));
+    let c = Circle::from_center_radius(P, 2.0).unwrap();
+    assert!(c.translate(by) == Circle::from_center_radius(P + by, 2.0).unwrap());
+    let s = Segment::from_2p(P, by).unwrap();
+    assert!(s.translate(by) == Segment::from_2p(P + by, by * 2.0).unwrap());
+}
+
+#[test]
+fn scale_circle_to_ellipse() {
+    let c = Circle::from_center_radius(Point::new(3.0, 4.0), 2.0).unwrap();
+    let O = Point::new(1.0, -1.0);
+    let e = scale_xy(c, O, 1.5, 1.5);
+    let d = c.scale(O, 1.5);
+    assert!(e.O == d.O && (e.a - d.r).abs() < 1e-10 && (e.b - d.r).abs() < 1e-10);
+    for i in 0..8 {
+        let P = e.point_on(i as f64);
+        assert!(d.is_through(P) && e.is_through(P));
+    }
+    let e = scale_xy(c, O, 2.0, -0.5);
+    for i in 0..8 {
+        let t = i as f64;
+        let P = c.point_on(t);
+        let Q = Point::new(O.x + (P.x - O.x) * 2.0, O.y - (P.y - O.y) * 0.5);
+        assert!(e.is_through(Q));
+        assert!(e.contains((Q + e.O) / 2.0));
+        assert!(!e.contains(Q * 2.0 - e.O));
+    }
+    // A rotated ellipse.
+    let f = Ellipse { angle: 0.5, ..e };
+    let P = f.point_on(1.0);
+    assert!(f.is_through(P) && f.on_boundary(P) && !f.contains(P));
+    assert!(f.contains(f.O + Point::new(0.5f64.cos(), 0.5f64.sin()) * (f.a - 0.1)));
+    assert!(!f.contains(f.O + Point::new(-(0.5f64.sin()), 0.5f64.cos()) * (f.b + 0.1)));
+}
+
+#[test]
+fn invert_segment() {
+    let O = Point::new(1.0, 1.0);
+    // Collinear with the center, the image is a Segment on the same side.
+    let s = Segment::from_2p(Point::new(2.0, 2.0), Point::new(3.0, 3.0)).unwrap();
+    match s.invert_in(O, 2.0).unwrap() {
+        ArcOrSegment::Segment(t) => {
+            assert!(t == Segment::from_2p(Point::new(2.0, 2.0), Point::new(1.5, 1.5)).unwrap())
+        }
+        r => panic!("expected a segment, got {:?}", r),
+    }
+    let s = Segment::from_2p(Point::new(0.0, 0.0), Point::new(3.0, 3.0)).unwrap();
+    assert_eq!(s.invert_in(O, 2.0).unwrap_err(), CalcException::Infinity);
+    // A general segment becomes the arc avoiding the center.
+    for (s, p) in [
+        (
+            Segment::from_2p(Point::new(2.0, 3.0), Point::new(2.0, -1.0)).unwrap(),
+            1.0,
+        ),
+        (
+            Segment::from_2p(Point::new(2.0, -1.0), Point::new(2.0, 3.0)).unwrap(),
+            1.0,
+        ),
+        (
+            Segment::from_2p(Point::new(2.0, 3.0), Point::new(4.0, 0.0)).unwrap(),
+            -3.0,
+        ),
+    ] {
+        match s.invert_in(O, p).unwrap() {
+            ArcOrSegment::Arc { c, start, end } => {
+                let (A, B) = (s.A.invert_in(O, p).unwrap(), s.B.invert_in(O, p).unwrap());
+                assert!((start == A && end == B) || (start == B && end == A));
+                assert!(c.is_through(A) && c.is_through(B) && c.is_through(O));
+                let span = signed_angle(start, c.O, end)
+                    .unwrap()
+                    .rem_euclid(std::f64::consts::TAU);
+                for t in [0.1, 0.5, 0.9] {
+                    let P = (s.A * (1.0 - t) + s.B * t).invert_in(O, p).unwrap();
+                    assert!(c.is_through(P));
+                    let a = signed_angle(start, c.O, P)
+                        .unwrap()
+                        .rem_euclid(std::f64::consts::TAU);
+                    assert!(a < span);
+                }
+            }
+            r => panic!("expected an arc, got {:?}", r),
+        }
+    }
+}
+
+#[test]
+fn transform_collections() {
+    let square = vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 2.0),
+        Point::new(0.0, 2.0),
+    ];
+    let O = Point::new(1.0, 1.0);
+    let rotated = square.clone().rotate(O, std::f64::consts::FRAC_PI_2);
+    assert_eq!(rotated.len(), 4);
+    for P in &square {
+        assert!(rotated.iter().any(|Q| Q == P));
+    }
+    assert!(rotated[0] == square[1]);
+    let l = Line::from_2p(O, Point::new(2.0, 2.0)).unwrap();
+    let p = Polygon(square.clone()).reflect_in(l);
+    assert!(p.0[1] == square[3] && p.0[0] == square[0]);
+    let p = Polygon(square.clone()).scale(O, 2.0).translate(O);
+    assert!((p.area() - 16.0).abs() < 1e-10 && p.centroid() == O * 2.0);
+    // Any transformable element type works, and slices via `to_vec`.
+    let lines = [l, Line::from_coeff(1.0, 0.0, -1.0).unwrap()].to_vec();
+    let moved = lines.translate(Point::new(0.0, 1.0));
+    assert!(moved[0].is_through(Point::new(1.0, 2.0)) && is_parallel(moved[0], l));
+    let dots = square[..2].to_vec().reflect_in(O);
+    assert!(dots[0] == square[2] && dots[1] == square[3]);
+}
+
+#[test]
+fn glide_reflection() {
+    let axis = Line::from_coeff(1.0, -2.0, 3.0).unwrap();
+    let by = axis.direction() * 3.0;
+    let P = Point::new(4.0, -1.0);
+    let Q = glide_reflect(P, axis, 1.5);
+    assert!((Q.distance(axis) - P.distance(axis)).abs() < 1e-10);
+    assert!(glide_reflect(Q, axis, 1.5) == P.translate(by));
+    let l = Line::from_coeff(3.0, 1.0, -2.0).unwrap();
+    // Reflecting a Line scales its coefficients, so compare the normalized forms.
+    let k = glide_reflect(glide_reflect(l, axis, 1.5), axis, 1.5);
+    assert!(k.normalized() == l.translate(by).normalized());
+    let c = Circle::from_center_radius(P, 2.0).unwrap();
+    assert!(glide_reflect(glide_reflect(c, axis, 1.5), axis, 1.5) == c.translate(by));
+    let p = Polygon(vec![P, Point::new(0.0, 0.0), Point::new(1.0, 5.0)]);
+    let q = glide_reflect(glide_reflect(p.clone(), axis, 1.5), axis, 1.5);
+    assert!(q.0.iter().zip(&p.0).all(|(&A, &B)| A == B.translate(by)));
+}
+
+#[test]
+fn spiral_similarities() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(2.0, 1.0);
+    let C = Point::new(5.0, -1.0);
+    let D = Point::new(4.0, 3.0);
+    let f = spiral_similarity(A, B, C, D).unwrap();
+    assert!(f.apply(A) == C && f.apply(B) == D);
+    let S = spiral_center(A, B, C, D).unwrap();
+    assert!(f.apply(S) == S);
+    // The center is on the circles through the intersection of `AB` and `CD`.
+    let X = Line::from_2p(A, B)
+        .unwrap()
+        .inter(Line::from_2p(C, D).unwrap())
+        .unwrap();
+    assert!(Circle::from_3p(A, C, X).unwrap().is_through(S));
+    assert!(Circle::from_3p(B, D, X).unwrap().is_through(S));
+    // A rotation about a known center.
+    let O = Point::new(1.0, 1.0);
+    let (E, F) = (
+        A.rotate(O, 1.0).scale(O, 2.0),
+        B.rotate(O, 1.0).scale(O, 2.0),
+    );
+    assert!(spiral_center(A, B, E, F).unwrap() == O);
+    assert_eq!(
+        spiral_center(A, B, C, C + B - A).unwrap_err(),
+        CalcException::Infinity
+    );
+    assert_eq!(
+        spiral_center(A, A, C, D).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+}
+
+#[test]
+fn mobius_transformations() {
+    let (zero, one) = (Point::new(0.0, 0.0), Point::new(1.0, 0.0));
+    let f = Mobius::from_three_pairs([Some(zero), Some(one), None], [Some(zero), Some(one), None])
+        .unwrap();
+    for P in [Point::new(2.0, -3.0), Point::new(0.5, 0.5), zero] {
+        assert!(f.apply(P).unwrap() == P);
+    }
+    let z = [
+        Point::new(1.0, 2.0),
+        Point::new(-1.0, 0.0),
+        Point::new(3.0, 1.0),
+    ];
+    let w = [
+        Point::new(0.0, 1.0),
+        Point::new(2.0, 2.0),
+        Point::new(-4.0, 0.5),
+    ];
+    let g = Mobius::from_three_pairs(z.map(Some), w.map(Some)).unwrap();
+    for i in 0..3 {
+        assert!(g.apply(z[i]).unwrap() == w[i]);
+    }
+    let h = g.compose(g.inverse().unwrap());
+    let P = Point::new(0.3, -0.7);
+    assert!(h.apply(P).unwrap() == P);
+    // Sending a point to infinity.
+    let k = Mobius::from_three_pairs(z.map(Some), [Some(zero), Some(one), None]).unwrap();
+    assert!(k.apply(z[2]).is_none());
+    // `z -> 1 / z`, the inversion in the unit circle followed by a conjugation.
+    let inv = Mobius {
+        a: zero,
+        b: one,
+        c: one,
+        d: zero,
+    };
+    let c = Circle::from_center_radius(Point::new(2.0, 0.0), 1.0).unwrap();
+    match inv
+        .apply_generalized_circle(LineInverted::Circle(c))
+        .unwrap()
+    {
+        LineInverted::Circle(d) => {
+            assert!(d.is_through(Point::new(1.0, 0.0)) && d.is_through(Point::new(1.0 / 3.0, 0.0)))
+        }
+        r => panic!("expected a circle, got {:?}", r),
+    }
+    // A circle through the pole `0` maps to a line.
+    let c = Circle::from_center_radius(Point::new(1.0, 0.0), 1.0).unwrap();
+    match inv
+        .apply_generalized_circle(LineInverted::Circle(c))
+        .unwrap()
+    {
+        LineInverted::Line(l) => {
+            assert!(l.is_through(Point::new(0.5, 0.0)) && l.is_through(Point::new(0.5, 4.0)))
+        }
+        r => panic!("expected a line, got {:?}", r),
+    }
+    let l = Line::from_coeff(1.0, 0.0, -0.5).unwrap();
+    match inv.apply_generalized_circle(LineInverted::Line(l)).unwrap() {
+        LineInverted::Circle(d) => assert!(d == c),
+        r => panic!("expected a circle, got {:?}", r),
+    }
+    for P in [Point::new(0.0, 1.0), Point::new(5.0, 5.0)] {
+        let l = Line::from_2p(zero, P).unwrap();
+        match g.apply_generalized_circle(LineInverted::Line(l)).unwrap() {
+            LineInverted::Circle(d) => {
+                assert!(d.is_through(g.apply(P).unwrap()) && d.is_through(g.apply(zero).unwrap()))
+            }
+            LineInverted::Line(m) => assert!(m.is_through(g.apply(P).unwrap())),
+        }
+    }
+    assert_eq!(
+        Mobius::from_three_pairs([Some(zero), Some(zero), None], w.map(Some)).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+}
+
+#[test]
+fn reflect_in_parallel_line() {
+    let l = Line::from_2p(Point::new(0.0, 3.0), Point::new(1.0, 3.0)).unwrap();
+    // The mirror `y = 1`, with differently scaled coefficients.
+    let k = Line::from_coeff(0.0, -3.0, 3.0).unwrap();
+    let m = l.reflect_in(k);
+    assert!(m.is_through(Point::new(5.0, -1.0)));
+    assert!(l.reflect_in(l).is_through(Point::new(-2.0, 3.0)));
+}
+
+#[test]
+fn inversion_edge_cases() {
+    let O = Point::new(1.0, 1.0);
+    // A Circle about the center of inversion used to panic.
+    let c = Circle::from_center_radius(O, 2.0).unwrap();
+    match c.invert_in(O, 8.0).unwrap() {
+        LineInverted::Circle(d) => assert!(d == Circle::from_center_radius(O, 4.0).unwrap()),
+        LineInverted::Line(_) => panic!("expected a Circle"),
+    }
+    // Zero power.
+    let l = Line::from_2p(Point::new(0.0, 3.0), Point::new(1.0, 3.0)).unwrap();
+    assert_eq!(
+        c.invert_in(O, 0.0).unwrap_err(),
+        CalcException::ZeroCoefficient
+    );
+    assert_eq!(
+        l.invert_in(O, 0.0).unwrap_err(),
+        CalcException::ZeroCoefficient
+    );
+    assert_eq!(
+        Point::new(3.0, 2.0).invert_in(O, 0.0).unwrap_err(),
+        CalcException::ZeroCoefficient
+    );
+}
+
+#[test]
+fn rotations_in_degrees() {
+    let O = Point::new(1.0, -1.0);
+    let P = Point::new(4.0, 2.0);
+    assert_eq!(
+        P.rotate_deg(O, 90.0),
+        P.rotate(O, std::f64::consts::FRAC_PI_2)
+    );
+    assert_eq!(
+        P.rotate_deg(O, -30.0),
+        P.rotate(O, -std::f64::consts::PI / 6.0)
+    );
+    let c = Circle::from_center_radius(O, 2.0).unwrap();
+    assert_eq!(c.rotate_deg(P, 180.0), c.reflect_in(P));
+    assert_eq!(c.point_on_deg(90.0), Point::new(1.0, 1.0));
+    assert_eq!(
+        c.point_on_deg(225.0),
+        c.point_on(1.25 * std::f64::consts::PI)
+    );
+}
@@ -0,0 +1,710 @@
+#![allow(non_snake_case)]
+
+use metric_rs::{
+    calc::{
+        basic::*,
+        constants::EPSILON,
+        construct::{angle_bisect_3p, midpoint, projection},
+        exception::CalcException,
+        point_on::PointOn,
+        transform::Reflect,
+        trig::{basic::*, centers::*, construct::*},
+    },
+    objects::*,
+};
+
+#[test]
+fn barycentric() {
+    let A = Point::new(1.0, 2.0);
+    let B = Point::new(-3.0, 0.5);
+    let C = Point::new(4.0, -1.0);
+    let m = barycentric_matrix((A, B, C)).unwrap();
+    for (i, P) in [A, B, C].iter().enumerate() {
+        for (j, row) in m.iter().enumerate() {
+            let u = row[0] * P.x + row[1] * P.y + row[2];
+            let e = if i == j { 1.0 } else { 0.0 };
+            assert!((u - e).abs() < 1e-10);
+        }
+    }
+    let n = barycentric_matrix_inv((A, B, C));
+    let P = Point::new(2.0, 3.0);
+    let u = m.map(|row| row[0] * P.x + row[1] * P.y + row[2]);
+    let Q = n.map(|row| row[0] * u[0] + row[1] * u[1] + row[2] * u[2]);
+    assert!(Point::new(Q[0], Q[1]) == P);
+    assert!((Q[2] - 1.0).abs() < 1e-10);
+    assert_eq!(
+        barycentric_matrix((A, B, (A + B) / 2.0)).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+}
+
+#[test]
+fn nine_point_configuration() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let P = nine_points((A, B, C)).unwrap();
+    let c = nine_point_circle((A, B, C)).unwrap();
+    assert!(c == Circle::from_3p(P[0], P[1], P[2]).unwrap());
+    for Q in P {
+        assert!(c.is_through(Q));
+    }
+}
+
+#[test]
+fn triangle_containment() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(4.0, 0.0);
+    let C = Point::new(0.0, 3.0);
+    let P = Point::new(1.0, 1.0);
+    let Q = Point::new(2.0, 0.0);
+    let R = Point::new(5.0, 0.0);
+    assert!(contains_point((A, B, C), P));
+    assert!(contains_point((A, C, B), P));
+    assert!(!contains_point((A, B, C), Q));
+    assert!(on_boundary((A, B, C), Q));
+    assert!(on_boundary((C, B, A), A));
+    assert!(!on_boundary((A, B, C), P));
+    assert!(!on_boundary((A, B, C), R));
+    assert!(!contains_point((A, B, C), R));
+}
+
+#[test]
+fn metric_quantities() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(4.0, 0.0);
+    let C = Point::new(0.0, 3.0);
+    assert_eq!(side_lengths((A, B, C)), (5.0, 3.0, 4.0));
+    assert_eq!(perimeter((A, B, C)), 12.0);
+    assert_eq!(area((A, B, C)), 6.0);
+    assert_eq!(signed_area((A, B, C)), 6.0);
+    assert_eq!(signed_area((A, C, B)), -6.0);
+    assert_eq!(area((A, C, B)), 6.0);
+}
+
+#[test]
+fn symmedian_point() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let K = symmedian((A, B, C)).unwrap();
+    let G = centroid((A, B, C));
+    assert!(K == isogonal_conjugate((A, B, C), G).unwrap());
+}
+
+#[test]
+fn gergonne_point() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    // The Gergonne point lies on the cevian from `A` to the touch point on `BC`.
+    let (a, b, c) = side_lengths((A, B, C));
+    let s = (a + b + c) / 2.0;
+    let D = B + (C - B) * ((s - b) / a);
+    let Ge = gergonne((A, B, C)).unwrap();
+    assert_eq!(orientation(A, D, Ge), 0);
+    let E = C + (A - C) * ((s - c) / b);
+    assert_eq!(orientation(B, E, Ge), 0);
+}
+
+#[test]
+fn interior_angles() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(4.0, 0.0);
+    let C = Point::new(0.0, 3.0);
+    let (a, b, c) = angles((A, B, C)).unwrap();
+    assert!((a - std::f64::consts::FRAC_PI_2).abs() < 1e-10);
+    assert!((b - (3.0f64).atan2(4.0)).abs() < 1e-10);
+    assert!((a + b + c - std::f64::consts::PI).abs() < 1e-10);
+    let D = Point::new(-5.0, 1.0);
+    let (a, b, c) = angles((A, B, D)).unwrap();
+    assert!(a > std::f64::consts::FRAC_PI_2);
+    assert!((a + b + c - std::f64::consts::PI).abs() < 1e-10);
+    assert_eq!(
+        angles((A, B, A)).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+}
+
+#[test]
+fn isogonal_conjugate_of_line() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let l = Line::from_2p(Point::new(1.0, 3.0), Point::new(4.0, 1.0)).unwrap();
+    let k = isogonal_conjugate_line((A, B, C), l).unwrap();
+    assert!(k.is_through(A));
+    assert!(k.is_through(B));
+    assert!(k.is_through(C));
+    for t in [0.0, 0.3, 1.0, 2.5] {
+        let P = Point::new(1.0, 3.0) + Point::new(3.0, -2.0) * t;
+        assert!(k.is_through(isogonal_conjugate((A, B, C), P).unwrap()));
+    }
+}
+
+#[test]
+fn triangle_circles() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let c = circumcircle((A, B, C)).unwrap();
+    assert!(c.is_through(A) && c.is_through(B) && c.is_through(C));
+    let i = incircle((A, B, C)).unwrap();
+    for l in [
+        Line::from_2p(A, B).unwrap(),
+        Line::from_2p(B, C).unwrap(),
+        Line::from_2p(C, A).unwrap(),
+    ] {
+        assert!((i.O.distance(l) - i.r).abs() < 1e-10);
+    }
+    let D = Point::new(14.0, 0.0);
+    assert_eq!(
+        circumcircle((A, B, D)).unwrap_err(),
+        CalcException::NoIntersection
+    );
+    assert_eq!(
+        incircle((A, B, D)).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+}
+
+#[test]
+fn triangle_excircles() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let (a, b, c) = excircles((A, B, C)).unwrap();
+    let sides = [
+        Line::from_2p(B, C).unwrap(),
+        Line::from_2p(C, A).unwrap(),
+        Line::from_2p(A, B).unwrap(),
+    ];
+    for e in [a, b, c] {
+        for l in sides {
+            assert!(e.distance(l).abs() < 1e-10);
+            assert!((e.O.distance(l) - e.r).abs() < 1e-10);
+        }
+    }
+    // The excircle opposite `A` lies beyond `BC`, away from `A`.
+    assert_ne!(orientation(B, C, a.O), orientation(B, C, A));
+    assert_ne!(orientation(C, A, b.O), orientation(C, A, B));
+    assert_ne!(orientation(A, B, c.O), orientation(A, B, C));
+}
+
+#[test]
+fn excenter_orientation() {
+    let A = Point::new(3.0, -1.0);
+    let B = Point::new(-2.0, 4.0);
+    let C = Point::new(5.0, 6.0);
+    assert_eq!(orientation(A, B, C), -1);
+    let J = excenter((A, B, C)).unwrap();
+    assert!(J == excenter((A, C, B)).unwrap());
+    // The excenter opposite `A` is equidistant from the three side lines.
+    let d = J.distance(Line::from_2p(B, C).unwrap());
+    assert!((J.distance(Line::from_2p(C, A).unwrap()) - d).abs() < 1e-10);
+    assert!((J.distance(Line::from_2p(A, B).unwrap()) - d).abs() < 1e-10);
+    assert_ne!(orientation(B, C, J), orientation(B, C, A));
+}
+
+#[test]
+fn incenter_for_every_ordering() {
+    let triangles = [
+        (
+            Point::new(3.0, -1.0),
+            Point::new(-2.0, 4.0),
+            Point::new(5.0, 6.0),
+        ),
+        (
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(-4.0, 1.0),
+        ),
+        (
+            Point::new(-7.0, -3.0),
+            Point::new(-6.5, 2.0),
+            Point::new(8.0, -2.5),
+        ),
+    ];
+    for (A, B, C) in triangles {
+        for t in [
+            (A, B, C),
+            (A, C, B),
+            (B, A, C),
+            (B, C, A),
+            (C, A, B),
+            (C, B, A),
+        ] {
+            let I = incenter(t).unwrap();
+            let d = I.distance(Line::from_2p(A, B).unwrap());
+            assert!((I.distance(Line::from_2p(B, C).unwrap()) - d).abs() < 1e-10);
+            assert!((I.distance(Line::from_2p(C, A).unwrap()) - d).abs() < 1e-10);
+            // Inside the triangle, so not an excenter.
+            let (u, v, w) = to_barycentric(t, I).unwrap();
+            assert!(u > 0.0 && v > 0.0 && w > 0.0);
+            // The first bisector from `angle_bisect_3p` is the interior one.
+            let (l, _) = angle_bisect_3p(t.1, t.0, t.2).unwrap();
+            assert!(l.is_through(I));
+        }
+    }
+}
+
+#[test]
+fn mixtilinear_touch() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let T = mixtilinear_touch_point((A, B, C)).unwrap();
+    assert!(circumcircle((A, B, C)).unwrap().is_through(T));
+    assert_ne!(orientation(B, C, T), orientation(B, C, A));
+    // The circle centered on `AI` and tangent to the circumcircle at `T` touches `AB`.
+    let O = circum((A, B, C)).unwrap();
+    let I = incenter((A, B, C)).unwrap();
+    let K = Line::from_2p(O, T)
+        .unwrap()
+        .inter(Line::from_2p(A, I).unwrap())
+        .unwrap();
+    let AB = Line::from_2p(A, B).unwrap();
+    assert!((K.distance(AB) - K.distance(T)).abs() < 1e-10);
+    let D = Point::new(14.0, 0.0);
+    assert!(mixtilinear_touch_point((A, B, D)).is_err());
+}
+
+#[test]
+fn nine_point_circle_incidence() {
+    let A = Point::new(-1.0, 0.5);
+    let B = Point::new(6.0, -2.0);
+    let C = Point::new(3.0, 4.0);
+    let c = nine_point_circle((A, B, C)).unwrap();
+    assert!(c.is_through(midpoint(A, B)));
+    assert!(c.is_through(midpoint(B, C)));
+    assert!(c.is_through(midpoint(C, A)));
+    assert!(c.is_through(projection(A, Line::from_2p(B, C).unwrap())));
+    assert!(c.is_through(projection(B, Line::from_2p(C, A).unwrap())));
+    assert!(c.is_through(projection(C, Line::from_2p(A, B).unwrap())));
+}
+
+#[test]
+fn euler_line_ratio() {
+    let A = Point::new(-1.0, 0.5);
+    let B = Point::new(6.0, -2.0);
+    let C = Point::new(3.0, 4.0);
+    let l = euler_line((A, B, C)).unwrap();
+    let (O, G, H) = (
+        circum((A, B, C)).unwrap(),
+        centroid((A, B, C)),
+        ortho((A, B, C)).unwrap(),
+    );
+    assert!(l.is_through(G));
+    assert!(l.is_through(nine_point((A, B, C)).unwrap()));
+    assert!(G == O + (H - O) / 3.0);
+    let D = Point::new(3.0f64.sqrt(), 1.0) * 2.0;
+    let E = Point::new(0.0, 4.0);
+    assert_eq!(
+        euler_line((Point::new(0.0, 0.0), D, E)).unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+}
+
+#[test]
+fn lemoine() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let (AB, BC, CA) = (
+        Line::from_2p(A, B).unwrap(),
+        Line::from_2p(B, C).unwrap(),
+        Line::from_2p(C, A).unwrap(),
+    );
+    let K = symmedian((A, B, C)).unwrap();
+    let (first, second) = lemoine_circles((A, B, C)).unwrap();
+    let (la, lb, lc) = (BC.parallel_at(K), CA.parallel_at(K), AB.parallel_at(K));
+    let P = [
+        la.inter(AB).unwrap(),
+        la.inter(CA).unwrap(),
+        lb.inter(AB).unwrap(),
+        lb.inter(BC).unwrap(),
+        lc.inter(CA).unwrap(),
+        lc.inter(BC).unwrap(),
+    ];
+    for Q in P {
+        assert!(first.is_through(Q));
+    }
+    // The antiparallel chords are equal.
+    let d = P[0].distance(P[5]);
+    assert!((P[1].distance(P[3]) - d).abs() < 1e-10);
+    assert!((P[2].distance(P[4]) - d).abs() < 1e-10);
+    assert!(second.O == K);
+    // The antiparallel to `BC` through `K` is a diameter of the second circle.
+    let (l, _) = angle_bisect_3p(B, A, C).unwrap();
+    let anti = Line::from_2p(B.reflect_in(l), C.reflect_in(l))
+        .unwrap()
+        .parallel_at(K);
+    assert!(second.is_through(anti.inter(AB).unwrap()));
+    assert!(second.is_through(anti.inter(CA).unwrap()));
+}
+
+#[test]
+fn medial_and_orthic() {
+    let A = Point::new(-1.0, 0.5);
+    let B = Point::new(6.0, -2.0);
+    let C = Point::new(3.0, 4.0);
+    let c = nine_point_circle((A, B, C)).unwrap();
+    let (D, E, F) = medial((A, B, C));
+    assert!(c.is_through(D) && c.is_through(E) && c.is_through(F));
+    assert!(circum((D, E, F)).unwrap() == c.O);
+    let (D, E, F) = orthic((A, B, C)).unwrap();
+    assert!(c.is_through(D) && c.is_through(E) && c.is_through(F));
+    let (D, E, F) = orthic((
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(0.0, 3.0),
+    ))
+    .unwrap();
+    assert!(E == Point::new(0.0, 0.0) && F == Point::new(0.0, 0.0));
+    assert!(D == Point::new(1.44, 1.92));
+}
+
+#[test]
+fn fermat() {
+    let A = Point::new(0.0, 0.0);
+    let D = Point::new(3.0f64.sqrt(), 1.0) * 2.0;
+    let E = Point::new(0.0, 4.0);
+    assert!(fermat_point((A, D, E)).unwrap() == centroid((A, D, E)));
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    for t in [(A, B, C), (A, C, B)] {
+        let F = fermat_point(t).unwrap();
+        // Each side subtends an angle of `2pi / 3` at the Fermat point.
+        for (P, Q) in [(t.0, t.1), (t.1, t.2), (t.2, t.0)] {
+            let (x, _, _) = angles((F, P, Q)).unwrap();
+            assert!((x - 2.0 * std::f64::consts::FRAC_PI_3).abs() < 1e-10);
+        }
+    }
+    let G = Point::new(3.5, 0.5);
+    assert!(fermat_point((A, B, G)).unwrap() == G);
+}
+
+#[test]
+fn circumcircle_tangents() {
+    let A = Point::new(-1.0, 0.5);
+    let B = Point::new(6.0, -2.0);
+    let C = Point::new(3.0, 4.0);
+    let O = circum((A, B, C)).unwrap();
+    for (i, P) in [A, B, C].into_iter().enumerate() {
+        let l = circumcircle_tangent_at_vertex((A, B, C), i).unwrap();
+        assert!(l.is_through(P));
+        let r = Line::from_2p(O, P).unwrap();
+        assert!((angle_between(l, r) - std::f64::consts::FRAC_PI_2).abs() < 1e-10);
+    }
+    let (D, E, F) = tangential_triangle((A, B, C)).unwrap();
+    assert!((D.distance(B) - D.distance(C)).abs() < 1e-10);
+    assert!(circumcircle_tangent_at_vertex((A, B, C), 2)
+        .unwrap()
+        .is_through(D));
+    assert!(circumcircle_tangent_at_vertex((A, B, C), 0)
+        .unwrap()
+        .is_through(E));
+    assert!(circumcircle_tangent_at_vertex((A, B, C), 1)
+        .unwrap()
+        .is_through(F));
+    assert_eq!(
+        circumcircle_tangent_at_vertex((A, B, C), 3).unwrap_err(),
+        CalcException::IndexOutOfRange
+    );
+    let right = (
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(0.0, 3.0),
+    );
+    assert_eq!(
+        tangential_triangle(right).unwrap_err(),
+        CalcException::NoIntersection
+    );
+}
+
+#[test]
+fn steiner_deltoid() {
+    let A = Point::new(-1.0, 0.5);
+    let B = Point::new(6.0, -2.0);
+    let C = Point::new(3.0, 4.0);
+    let N = nine_point((A, B, C)).unwrap();
+    let R = circumcircle((A, B, C)).unwrap().r;
+    let P = steiner_deltoid_points((A, B, C), 64).unwrap();
+    assert_eq!(P.len(), 64);
+    // The deltoid lies between the nine-point circle and the circle of thrice its radius.
+    for Q in P {
+        let d = Q.distance(N);
+        assert!(d > R / 2.0 - 1e-6 && d < 1.5 * R + 1e-6);
+    }
+    assert!(steiner_deltoid_points((A, B, C), 0).unwrap().is_empty());
+}
+
+#[test]
+fn napoleon() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    for t in [(A, B, C), (A, C, B)] {
+        for outward in [true, false] {
+            let (D, E, F) = napoleon_triangle(t, outward).unwrap();
+            let d = D.distance(E);
+            assert!((E.distance(F) - d).abs() < 1e-10);
+            assert!((F.distance(D) - d).abs() < 1e-10);
+            assert!(centroid((D, E, F)) == centroid(t));
+        }
+        // The outer center on `BC` lies beyond `BC`.
+        let (D, _, _) = napoleon_triangle(t, true).unwrap();
+        assert_ne!(orientation(t.1, t.2, D), orientation(t.1, t.2, t.0));
+    }
+    assert_eq!(
+        napoleon_triangle((A, B, Point::new(14.0, 0.0)), true).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+}
+
+#[test]
+fn feuerbach_tangent_touches_both_circles() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let l = feuerbach_tangent((A, B, C)).unwrap();
+    let i = incircle((A, B, C)).unwrap();
+    let n = nine_point_circle((A, B, C)).unwrap();
+    assert!((i.O.distance(l) - i.r).abs() < 1e-10);
+    assert!((n.O.distance(l) - n.r).abs() < 1e-10);
+    assert_eq!(
+        feuerbach_tangent((A, B, Point::new(14.0, 0.0))).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+}
+
+#[test]
+fn feuerbach_point_on_both_circles() {
+    let t = (
+        Point::new(0.0, 0.0),
+        Point::new(7.0, 0.0),
+        Point::new(2.0, 5.0),
+    );
+    let F = feuerbach_point(t).unwrap();
+    let i = incircle(t).unwrap();
+    let n = nine_point_circle(t).unwrap();
+    assert!((F.distance(i.O) - i.r).abs() < EPSILON);
+    assert!((F.distance(n.O) - n.r).abs() < EPSILON);
+    assert!(feuerbach_tangent(t).unwrap().is_through(F));
+    let s = 3f64.sqrt();
+    assert_eq!(
+        feuerbach_point((
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, s)
+        ))
+        .unwrap_err(),
+        CalcException::OverlappingPoint
+    );
+}
+
+#[test]
+fn mixtilinear_incircle_tangency() {
+    let (A, B, C) = (
+        Point::new(0.0, 0.0),
+        Point::new(7.0, 0.0),
+        Point::new(2.0, 5.0),
+    );
+    let m = mixtilinear_incircle((A, B, C)).unwrap();
+    let c = circumcircle((A, B, C)).unwrap();
+    assert!((m.O.distance(Line::from_2p(A, B).unwrap()) - m.r).abs() < 1e-10);
+    assert!((m.O.distance(Line::from_2p(A, C).unwrap()) - m.r).abs() < 1e-10);
+    assert!((m.O.distance(c.O) - (c.r - m.r)).abs() < 1e-10);
+    let T = mixtilinear_touch_point((A, B, C)).unwrap();
+    assert!(m.is_through(T));
+    assert_eq!(
+        mixtilinear_incircle((A, B, Point::new(14.0, 0.0))).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+}
+
+#[test]
+fn barycentric_round_trip() {
+    let A = Point::new(1.0, 2.0);
+    let B = Point::new(-3.0, 0.5);
+    let C = Point::new(4.0, -1.0);
+    for t in [(A, B, C), (A, C, B)] {
+        for P in [Point::new(2.0, 3.0), Point::new(0.5, 0.5), A, (B + C) / 2.0] {
+            let (x, y, z) = to_barycentric(t, P).unwrap();
+            assert!((x + y + z - 1.0).abs() < 1e-10);
+            assert!(from_barycentric(t, (x, y, z)).unwrap() == P);
+        }
+    }
+    let (x, y, z) = to_barycentric((A, B, C), centroid((A, B, C))).unwrap();
+    assert!((x - 1.0 / 3.0).abs() < 1e-10 && (y - z).abs() < 1e-10);
+    assert_eq!(
+        to_barycentric((A, B, (A + B) / 2.0), C).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+}
+
+#[test]
+fn trilinear() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let t = (A, B, C);
+    assert!(from_trilinear(t, (1.0, 1.0, 1.0)).unwrap() == incenter(t).unwrap());
+    let (a, b, c) = angles(t).unwrap();
+    assert!(from_trilinear(t, (a.cos(), b.cos(), c.cos())).unwrap() == circum(t).unwrap());
+    let r = incircle(t).unwrap().r;
+    let (x, y, z) = to_trilinear(t, incenter(t).unwrap()).unwrap();
+    assert!((x - r).abs() < 1e-10 && (y - r).abs() < 1e-10 && (z - r).abs() < 1e-10);
+    let P = Point::new(3.0, -2.0);
+    assert!(from_trilinear(t, to_trilinear(t, P).unwrap()).unwrap() == P);
+    assert_eq!(
+        from_trilinear(t, (0.0, 0.0, 0.0)).unwrap_err(),
+        CalcException::ZeroCoefficient
+    );
+}
+
+#[test]
+fn contact_triangle_touch_points() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let i = incircle((A, B, C)).unwrap();
+    let (D, E, F) = contact_triangle((A, B, C)).unwrap();
+    for (P, (X, Y)) in [(D, (B, C)), (E, (C, A)), (F, (A, B))] {
+        assert!(Line::from_2p(X, Y).unwrap().is_through(P));
+        assert!(i.is_through(P));
+    }
+    let G = gergonne((A, B, C)).unwrap();
+    assert!(Line::from_2p(A, D).unwrap().is_through(G));
+    assert!(Line::from_2p(B, E).unwrap().is_through(G));
+    assert_eq!(
+        contact_triangle((A, B, Point::new(14.0, 0.0))).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+}
+
+#[test]
+fn pedal_and_simson() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let t = (A, B, C);
+    let (D, E, F) = pedal_triangle(t, ortho(t).unwrap()).unwrap();
+    let (X, Y, Z) = orthic(t).unwrap();
+    assert!(D == X && E == Y && F == Z);
+    let c = circumcircle(t).unwrap();
+    for theta in [0.3, 1.7, 4.0] {
+        let P = c.point_on(theta);
+        let (D, E, F) = pedal_triangle(t, P).unwrap();
+        assert!(area((D, E, F)) < 1e-9);
+        let l = simson_line(t, P).unwrap();
+        assert!(l.is_through(D) && l.is_through(E) && l.is_through(F));
+    }
+    assert!(simson_line(t, A)
+        .unwrap()
+        .is_through(projection(A, Line::from_2p(B, C).unwrap())));
+    assert_eq!(
+        simson_line(t, centroid(t)).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+}
+
+#[test]
+fn simson_at_large_scale() {
+    let s = 1000.0;
+    let O = Point::new(2e5, -3e5);
+    let t = (
+        O,
+        O + Point::new(7.0 * s, 0.0),
+        O + Point::new(2.0 * s, 5.0 * s),
+    );
+    let c = circumcircle(t).unwrap();
+    for theta in [0.3, 1.7, 4.0] {
+        let P = c.point_on(theta);
+        let (D, E, F) = pedal_triangle(t, P).unwrap();
+        let l = simson_line(t, P).unwrap();
+        assert!(D.distance(l) < 1e-6 && E.distance(l) < 1e-6 && F.distance(l) < 1e-6);
+    }
+    assert_eq!(
+        simson_line(t, centroid(t)).unwrap_err(),
+        CalcException::CollinearPoints
+    );
+    let N = nine_point(t).unwrap();
+    let P = steiner_deltoid_points(t, 12).unwrap();
+    assert_eq!(P.len(), 12);
+    for Q in P {
+        let d = Q.distance(N);
+        assert!(d > c.r / 2.0 * (1.0 - 1e-6) && d < 1.5 * c.r * (1.0 + 1e-6));
+    }
+}
+
+#[test]
+fn isotomic() {
+    let A = Point::new(0.0, 0.0);
+    let B = Point::new(7.0, 0.0);
+    let C = Point::new(2.0, 5.0);
+    let t = (A, B, C);
+    let G = centroid(t);
+    assert!(isotomic_conjugate(t, G).unwrap() == G);
+    let P = Point::new(3.0, 1.0);
+    let Q = isotomic_conjugate(t, P).unwrap();
+    assert!(isotomic_conjugate(t, Q).unwrap() == P);
+    // The cevian feet of `P` and `Q` are symmetric about the side midpoints.
+    let a = Line::from_2p(B, C).unwrap();
+    let D = Line::from_2p(A, P).unwrap().inter(a).unwrap();
+    let E = Line::from_2p(A, Q).unwrap().inter(a).unwrap();
+    assert!(midpoint(D, E) == midpoint(B, C));
+    assert_eq!(
+        isotomic_conjugate(t, midpoint(A, B)).unwrap_err(),
+        CalcException::Infinity
+    );
+}
+
+#[test]
+fn nagel_line_centers() {
+    let t = (
+        Point::new(0.0, 0.0),
+        Point::new(7.0, 0.0),
+        Point::new(2.0, 5.0),
+    );
+    let I = incenter(t).unwrap();
+    let G = centroid(t);
+    let N = nagel_point(t).unwrap();
+    // The Nagel line, with `IG : GN = 1 : 2` and the Spieker center midway between `I`, `N`.
+    assert!(Line::from_2p(I, G).unwrap().is_through(N));
+    assert_eq!(N, G * 3.0 - I * 2.0);
+    assert_eq!(spieker_center(t).unwrap(), midpoint(I, N));
+    // The mittenpunkt is on the line through the centroid and the Gergonne point.
+    let M = mittenpunkt(t).unwrap();
+    assert!(Line::from_2p(G, gergonne(t).unwrap())
+        .unwrap()
+        .is_through(M));
+    let (A, B, C) = t;
+    let excentral = (
+        excenter((A, B, C)).unwrap(),
+        excenter((B, C, A)).unwrap(),
+        excenter((C, A, B)).unwrap(),
+    );
+    assert_eq!(M, symmedian(excentral).unwrap());
+}
+
+#[test]
+fn center_functions() {
+    let t = (
+        Point::new(1.0, 2.0),
+        Point::new(-3.0, 0.5),
+        Point::new(4.0, -1.0),
+    );
+    assert_eq!(
+        triangle_center(t, |_, _, _| 1.0).unwrap(),
+        incenter(t).unwrap()
+    );
+    assert_eq!(triangle_center(t, |a, _, _| 1.0 / a).unwrap(), centroid(t));
+    // The circumcenter is `cos A : cos B : cos C`.
+    let cos = |a: f64, b: f64, c: f64| (b * b + c * c - a * a) / (2.0 * b * c);
+    assert_eq!(triangle_center(t, cos).unwrap(), circum(t).unwrap());
+    assert_eq!(
+        triangle_center(t, |_, _, _| 0.0).unwrap_err(),
+        CalcException::ZeroCoefficient
+    );
+}